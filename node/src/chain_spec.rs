@@ -2,8 +2,10 @@
 
 use cumulus_primitives_core::ParaId;
 use basilisk_runtime::{
-	AccountId, AuraId, AuraConfig, AssetRegistryConfig, BalancesConfig, FaucetConfig, GenesisConfig, ParachainInfoConfig, Signature,
-	SudoConfig, SystemConfig, TokensConfig, CORE_ASSET_ID, WASM_BINARY,
+	AccountId, AssetDetailsConfig, AssetRegistryConfig, AuraConfig, AuraId, Balance, BalancesConfig, BlockNumber,
+	CandidacyBond, CollatorSelectionConfig, CouncilConfig, DemocracyConfig, DesiredCandidates, FaucetConfig,
+	GenesisConfig, ParachainInfoConfig, SessionConfig, SessionKeys, Signature, SudoConfig, SystemConfig,
+	TechnicalCommitteeConfig, TokensConfig, VestingConfig, CORE_ASSET_ID, DAYS, UNITS, WASM_BINARY,
 };
 use hex_literal::hex;
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
@@ -68,13 +70,26 @@ pub fn roccocco_parachain_config(para_id: ParaId) -> Result<ChainSpec, String> {
 				wasm_binary,
 				// Sudo account
 				hex!["30035c21ba9eda780130f2029a80c3e962f56588bc04c36be95a225cb536fb55"].into(),
+				// Invulnerable collators
 				// TODO generate real authorities
 				vec![
-					get_from_seed::<AuraId>("Alice"),
-					get_from_seed::<AuraId>("Bob"),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Alice"),
+						get_from_seed::<AuraId>("Alice"),
+					),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Bob"),
+						get_from_seed::<AuraId>("Bob"),
+					),
 				],
 				// Pre-funded accounts
 				vec![hex!["30035c21ba9eda780130f2029a80c3e962f56588bc04c36be95a225cb536fb55"].into()],
+				// Council members
+				vec![hex!["30035c21ba9eda780130f2029a80c3e962f56588bc04c36be95a225cb536fb55"].into()],
+				// Technical committee members
+				vec![hex!["30035c21ba9eda780130f2029a80c3e962f56588bc04c36be95a225cb536fb55"].into()],
+				// Vesting schedules
+				vec![],
 				true,
 				para_id,
 			)
@@ -111,9 +126,16 @@ pub fn parachain_development_config(para_id: ParaId) -> Result<ChainSpec, String
 				wasm_binary,
 				// Sudo account
 				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				// Invulnerable collators
 				vec![
-					get_from_seed::<AuraId>("Alice"),
-					get_from_seed::<AuraId>("Bob"),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Alice"),
+						get_from_seed::<AuraId>("Alice"),
+					),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Bob"),
+						get_from_seed::<AuraId>("Bob"),
+					),
 				],
 				// Pre-funded accounts
 				vec![
@@ -122,6 +144,18 @@ pub fn parachain_development_config(para_id: ParaId) -> Result<ChainSpec, String
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
+				// Technical committee members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
+				// Vesting schedules
+				vec![],
 				true,
 				para_id,
 			)
@@ -159,9 +193,16 @@ pub fn local_parachain_config(para_id: ParaId) -> Result<ChainSpec, String> {
 				wasm_binary,
 				// Sudo account
 				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				// Invulnerable collators
 				vec![
-					get_from_seed::<AuraId>("Alice"),
-					get_from_seed::<AuraId>("Bob"),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Alice"),
+						get_from_seed::<AuraId>("Alice"),
+					),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Bob"),
+						get_from_seed::<AuraId>("Bob"),
+					),
 				],
 				// Pre-funded accounts
 				vec![
@@ -178,6 +219,25 @@ pub fn local_parachain_config(para_id: ParaId) -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
+				// Technical committee members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Dave"),
+					get_account_id_from_seed::<sr25519::Public>("Eve"),
+				],
+				// Vesting schedules: (who, start, period, period count, amount per period)
+				vec![(
+					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+					0,
+					DAYS,
+					30,
+					1_000 * UNITS,
+				)],
 				true,
 				para_id,
 			)
@@ -202,8 +262,11 @@ pub fn local_parachain_config(para_id: ParaId) -> Result<ChainSpec, String> {
 fn parachain_genesis(
 	wasm_binary: &[u8],
 	root_key: AccountId,
-	initial_authorities: Vec<AuraId>,
+	initial_authorities: Vec<(AccountId, AuraId)>,
 	endowed_accounts: Vec<AccountId>,
+	council_members: Vec<AccountId>,
+	tech_committee_members: Vec<AccountId>,
+	vesting: Vec<(AccountId, BlockNumber, BlockNumber, u32, Balance)>,
 	_enable_println: bool,
 	parachain_id: ParaId,
 ) -> GenesisConfig {
@@ -225,9 +288,31 @@ fn parachain_genesis(
 			// Assign network admin rights.
 			key: root_key,
 		},
-		pallet_aura: AuraConfig {
-			authorities: initial_authorities,
+		pallet_collective_Instance1: CouncilConfig {
+			members: council_members,
+			phantom: Default::default(),
+		},
+		pallet_collective_Instance2: TechnicalCommitteeConfig {
+			members: tech_committee_members,
+			phantom: Default::default(),
 		},
+		pallet_democracy: DemocracyConfig::default(),
+		pallet_treasury: Default::default(),
+		pallet_collator_selection: CollatorSelectionConfig {
+			invulnerables: initial_authorities.iter().cloned().map(|(acc, _)| acc).collect(),
+			candidacy_bond: CandidacyBond::get(),
+			desired_candidates: DesiredCandidates::get(),
+		},
+		pallet_session: SessionConfig {
+			keys: initial_authorities
+				.iter()
+				.cloned()
+				// (account id, validator id, session keys)
+				.map(|(acc, aura)| (acc.clone(), acc, SessionKeys { aura }))
+				.collect(),
+		},
+		// no need to pass anything, the session pallet populates the authorities
+		pallet_aura: AuraConfig { authorities: vec![] },
 		pallet_asset_registry: AssetRegistryConfig {
 			core_asset_id: CORE_ASSET_ID,
 			asset_ids: vec![
@@ -238,6 +323,15 @@ fn parachain_genesis(
 			],
 			next_asset_id: 5,
 		},
+		pallet_asset_details: AssetDetailsConfig {
+			// 0.001 of a 12 decimals token
+			existential_deposits: vec![
+				(1, 1_000_000_000u128),
+				(2, 1_000_000_000u128),
+				(3, 1_000_000_000u128),
+				(4, 1_000_000_000u128),
+			],
+		},
 		orml_tokens: TokensConfig {
 			endowed_accounts: endowed_accounts
 				.iter()
@@ -251,6 +345,8 @@ fn parachain_genesis(
 				})
 				.collect(),
 		},
+		pallet_duster: Default::default(),
+		orml_vesting: VestingConfig { vesting },
 		parachain_info: ParachainInfoConfig { parachain_id },
 		pallet_faucet: FaucetConfig {
 			rampage: false,
@@ -33,9 +33,11 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: pallet_xyk_rpc::XYKRuntimeApi<Block, AccountId, AssetId, Balance>,
+	C::Api: identity_rpc::IdentityRuntimeApi<Block, AccountId>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use identity_rpc::{Identity, IdentityApi};
 	use pallet_xyk_rpc::{XYKApi, XYK};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
@@ -62,7 +64,9 @@ where
 	// to call into the runtime.
 	// `io.extend_with(YourRpcTrait::to_delegate(YourRpcStruct::new(ReferenceToClient, ...)));`
 
-	io.extend_with(XYKApi::to_delegate(XYK::new(client)));
+	io.extend_with(XYKApi::to_delegate(XYK::new(client.clone())));
+
+	io.extend_with(IdentityApi::to_delegate(Identity::new(client)));
 
 	io
 }
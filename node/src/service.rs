@@ -11,16 +11,19 @@ use cumulus_client_service::{
 	prepare_node_config, start_collator, start_full_node, StartCollatorParams, StartFullNodeParams,
 };
 use cumulus_primitives_core::ParaId;
+use cumulus_primitives_parachain_inherent::ParachainInherentData;
 use basilisk_runtime::{self, RuntimeApi};
-use polkadot_primitives::v1::CollatorPair;
+use polkadot_primitives::v1::{Block as PBlock, CollatorPair, Hash as PHash};
 use sc_executor::native_executor_instance;
-use sc_client_api::ExecutorProvider;
+use sc_client_api::{Backend, ExecutorProvider};
 use sc_network::NetworkService;
 pub use sc_executor::NativeExecutor;
 use sc_service::{Configuration, PartialComponents, Role, TaskManager, TFullBackend, TFullClient};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
 use sp_consensus::SlotData;
 use sp_keystore::SyncCryptoStorePtr;
+use sp_runtime::generic::BlockId;
+use sp_trie::StorageProof;
 use std::sync::Arc;
 use substrate_prometheus_endpoint::Registry;
 
@@ -303,6 +306,34 @@ pub fn parachain_build_import_queue(
 		.map_err(Into::into)
 }
 
+/// Adds the BABE randomness key to the relay chain state proof of the parachain inherent.
+///
+/// The proof created by cumulus only contains the keys `cumulus_pallet_parachain_system` reads,
+/// `pallet_relaychain_info` reads the relay chain randomness from the same proof.
+fn with_relay_randomness_proof<B: Backend<PBlock>>(
+	mut parachain_inherent: ParachainInherentData,
+	relay_parent: PHash,
+	relay_chain_backend: &B,
+) -> ParachainInherentData {
+	let randomness_proof = relay_chain_backend
+		.state_at(BlockId::Hash(relay_parent))
+		.map_err(|e| e.to_string())
+		.and_then(|state| {
+			sp_state_machine::prove_read(state, &[pallet_relaychain_info::relay_babe_randomness_key()])
+				.map_err(|e| e.to_string())
+		});
+
+	match randomness_proof {
+		Ok(proof) => {
+			parachain_inherent.relay_chain_state =
+				StorageProof::merge(vec![parachain_inherent.relay_chain_state, proof]);
+		}
+		Err(e) => log::warn!("Failed to prove relay chain randomness at {:?}: {}", relay_parent, e),
+	}
+
+	parachain_inherent
+}
+
 /// Start a normal parachain node.
 #[sc_tracing::logging::prefix_logs_with("Parachain")]
 pub async fn start_node(
@@ -360,7 +391,10 @@ pub async fn start_node(
 							&*relay_chain_backend,
 							&validation_data,
 							para_id,
-						);
+						)
+						.map(|parachain_inherent| {
+							with_relay_randomness_proof(parachain_inherent, relay_parent, &*relay_chain_backend)
+						});
 					async move {
 						let time = sp_timestamp::InherentDataProvider::from_system_time();
 
@@ -0,0 +1,111 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use codec::Decode;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 1;
+
+fn register_asset<T: Config>(name: Vec<u8>) -> T::AssetId {
+	let origin = T::RegistryOrigin::successful_origin();
+	Pallet::<T>::register(origin, name.clone(), T::Balance::from(1_000u32)).expect("asset is registered");
+	pallet_asset_registry::Module::<T>::get_or_create_asset(name).expect("asset is in the registry")
+}
+
+fn seed_name(seed: u32) -> Vec<u8> {
+	let mut name = b"benchmark".to_vec();
+	name.extend_from_slice(&seed.to_le_bytes());
+	name
+}
+
+/// Locations are opaque to the pallet, so benchmarks decode them from bytes. The prefix decodes to
+/// `X2(Parent, ..)` as a `MultiLocation`, which is not reserved by the runtime.
+fn location<T: Config>(byte: u8) -> T::AssetLocation {
+	let mut bytes = [byte; 64];
+	bytes[..2].copy_from_slice(&[2, 0]);
+	T::AssetLocation::decode(&mut &bytes[..]).expect("location decodes from bytes")
+}
+
+benchmarks! {
+	register {
+		let origin = T::RegistryOrigin::successful_origin();
+		let name = seed_name(SEED);
+		let call = Call::<T>::register(name.clone(), T::Balance::from(1_000u32));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let asset_id = pallet_asset_registry::Module::<T>::get_or_create_asset(name).unwrap();
+		assert!(Assets::<T>::contains_key(asset_id));
+	}
+
+	set_existential_deposit {
+		let asset_id = register_asset::<T>(seed_name(SEED));
+		let origin = T::RegistryOrigin::successful_origin();
+		let call = Call::<T>::set_existential_deposit(asset_id, T::Balance::from(2_000u32));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Pallet::<T>::existential_deposit(asset_id), T::Balance::from(2_000u32));
+	}
+
+	set_location {
+		let asset_id = register_asset::<T>(seed_name(SEED));
+		let origin = T::RegistryOrigin::successful_origin();
+
+		// worst case replaces a previous location of the asset
+		Pallet::<T>::set_location(origin.clone(), asset_id, location::<T>(0)).expect("location is set");
+		let new_location = location::<T>(2);
+		let call = Call::<T>::set_location(asset_id, new_location.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(LocationAssets::<T>::get(new_location), Some(asset_id));
+	}
+
+	claim_unknown_balance {
+		let asset_id = register_asset::<T>(seed_name(SEED));
+		let location = location::<T>(0);
+		Pallet::<T>::set_location(T::RegistryOrigin::successful_origin(), asset_id, location.clone())
+			.expect("location is set");
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::UnknownBalances::put(&caller, &location, T::Balance::from(10_000u32));
+	}: _(RawOrigin::Signed(caller.clone()), asset_id)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_id, &caller), T::Balance::from(10_000u32));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_register::<Test>());
+			assert_ok!(test_benchmark_set_existential_deposit::<Test>());
+			assert_ok!(test_benchmark_set_location::<Test>());
+			assert_ok!(test_benchmark_claim_unknown_balance::<Test>());
+		});
+	}
+}
@@ -0,0 +1,364 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset details pallet
+//!
+//! Extends the assets of `pallet_asset_registry` with details the registry does not hold, such as
+//! the existential deposit of each asset.
+//!
+//! Assets registered through this pallet carry an existential deposit which is enforced by
+//! `orml_tokens` through `existential_deposit`. Assets created directly in the registry (e.g. XYK
+//! share tokens) fall back to `DefaultExistentialDeposit`. The existential deposit of the native
+//! asset is fixed by `NativeExistentialDeposit` because it is enforced by `pallet_balances`.
+//!
+//! Registered assets can optionally carry a location (e.g. an XCM `MultiLocation` of their reserve)
+//! which identifies the asset when it is transferred from other chains. A location belongs to at
+//! most one asset and can not be one of `ReservedLocations`.
+//!
+//! Balances of an asset received before its location was set are held by `UnknownBalances`. Once
+//! the location is set, accounts claim them as the registered asset with `claim_unknown_balance`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	sp_runtime::RuntimeDebug,
+	traits::{Contains, Get},
+	transactional,
+};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Details of a registered asset.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetDetails<Balance> {
+	/// Minimum balance of the asset an account must hold.
+	pub existential_deposit: Balance,
+}
+
+/// Balances of assets received before the location of the asset was set.
+pub trait UnknownBalances<AccountId, AssetLocation, Balance> {
+	/// Remove the balance `who` holds under `location` and return it.
+	fn take(who: &AccountId, location: &AssetLocation) -> Balance;
+
+	/// Hold `amount` under `location` for `who`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn put(who: &AccountId, location: &AssetLocation, amount: Balance);
+}
+
+impl<AccountId, AssetLocation, Balance: Zero> UnknownBalances<AccountId, AssetLocation, Balance> for () {
+	fn take(_who: &AccountId, _location: &AssetLocation) -> Balance {
+		Zero::zero()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn put(_who: &AccountId, _location: &AssetLocation, _amount: Balance) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_asset_registry::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Balance type of the assets.
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize;
+
+		/// Origin which may register assets and update their details.
+		type RegistryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Native asset id.
+		type NativeAssetId: Get<Self::AssetId>;
+
+		/// Existential deposit of the native asset.
+		type NativeExistentialDeposit: Get<Self::Balance>;
+
+		/// Existential deposit of assets without details.
+		type DefaultExistentialDeposit: Get<Self::Balance>;
+
+		/// Location of an asset, such as its reserve chain.
+		type AssetLocation: Parameter + Member;
+
+		/// Locations which can not be set for an asset, e.g. locations the runtime already maps to
+		/// an asset.
+		type ReservedLocations: Contains<Self::AssetLocation>;
+
+		/// Currency in which claimed unknown balances are deposited.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Self::Balance>;
+
+		/// Balances of assets received before their location was set.
+		type UnknownBalances: UnknownBalances<Self::AccountId, Self::AssetLocation, Self::Balance>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Asset already has details.
+		AssetAlreadyRegistered,
+		/// Asset has no details.
+		AssetNotRegistered,
+		/// Details of the native asset are fixed by the runtime.
+		NativeAssetDetailsFixed,
+		/// Location is already used by another asset.
+		LocationAlreadyRegistered,
+		/// Location is reserved and can not be set for an asset.
+		LocationReserved,
+		/// Asset has no location.
+		LocationNotSet,
+		/// Account holds no balance of the asset under its location.
+		NothingToClaim,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		T::AssetId = "AssetId",
+		T::Balance = "Balance",
+		T::AssetLocation = "AssetLocation"
+	)]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Asset was registered. \[asset id, name, existential deposit\]
+		Registered(T::AssetId, Vec<u8>, T::Balance),
+
+		/// Existential deposit of an asset was updated. \[asset id, existential deposit\]
+		ExistentialDepositUpdated(T::AssetId, T::Balance),
+
+		/// Location of an asset was set. \[asset id, location\]
+		LocationSet(T::AssetId, T::AssetLocation),
+
+		/// Balance received before the location of an asset was set was claimed.
+		/// \[asset id, who, amount\]
+		UnknownBalanceClaimed(T::AssetId, T::AccountId, T::Balance),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_details)]
+	/// Details of registered assets.
+	pub type Assets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, AssetDetails<T::Balance>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn asset_location)]
+	/// Locations of registered assets.
+	pub type AssetLocations<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, T::AssetLocation, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn location_asset)]
+	/// Assets by their location.
+	pub type LocationAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetLocation, T::AssetId, OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Existential deposits of assets registered in the asset registry genesis.
+		pub existential_deposits: Vec<(T::AssetId, T::Balance)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig {
+				existential_deposits: vec![],
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset_id, existential_deposit) in self.existential_deposits.iter() {
+				Assets::<T>::insert(
+					asset_id,
+					AssetDetails {
+						existential_deposit: *existential_deposit,
+					},
+				);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register an asset with given existential deposit.
+		///
+		/// Creates the asset in the asset registry if it does not exist yet.
+		///
+		/// Emits `Registered` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			existential_deposit: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let asset_id = pallet_asset_registry::Module::<T>::get_or_create_asset(name.clone())?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetDetailsFixed);
+			ensure!(!Assets::<T>::contains_key(asset_id), Error::<T>::AssetAlreadyRegistered);
+
+			Assets::<T>::insert(asset_id, AssetDetails { existential_deposit });
+
+			Self::deposit_event(Event::Registered(asset_id, name, existential_deposit));
+
+			Ok(().into())
+		}
+
+		/// Update existential deposit of a registered asset.
+		///
+		/// Accounts already holding less than the new existential deposit are not affected until
+		/// their balance changes.
+		///
+		/// Emits `ExistentialDepositUpdated` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::set_existential_deposit())]
+		pub fn set_existential_deposit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			existential_deposit: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			Self::update_details(asset_id, |details| {
+				details.existential_deposit = existential_deposit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ExistentialDepositUpdated(asset_id, existential_deposit));
+
+			Ok(().into())
+		}
+
+		/// Set or update location of a registered asset.
+		///
+		/// The previous location of the asset no longer identifies it. Setting the current location
+		/// of the asset again does nothing.
+		///
+		/// Emits `LocationSet` event when the location changed.
+		#[pallet::weight(<T as Config>::WeightInfo::set_location())]
+		pub fn set_location(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			location: T::AssetLocation,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetDetailsFixed);
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotRegistered);
+			ensure!(!T::ReservedLocations::contains(&location), Error::<T>::LocationReserved);
+
+			let previous = AssetLocations::<T>::get(asset_id);
+			if previous.as_ref() == Some(&location) {
+				return Ok(().into());
+			}
+
+			ensure!(!LocationAssets::<T>::contains_key(&location), Error::<T>::LocationAlreadyRegistered);
+
+			if let Some(previous) = previous {
+				LocationAssets::<T>::remove(previous);
+			}
+			AssetLocations::<T>::insert(asset_id, &location);
+			LocationAssets::<T>::insert(&location, asset_id);
+
+			Self::deposit_event(Event::LocationSet(asset_id, location));
+
+			Ok(().into())
+		}
+
+		/// Claim the balance of an asset the caller received before the location of the asset was
+		/// set.
+		///
+		/// The balance held by `UnknownBalances` under the current location of the asset is
+		/// deposited to the caller as the registered asset.
+		///
+		/// Emits `UnknownBalanceClaimed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::claim_unknown_balance())]
+		#[transactional]
+		pub fn claim_unknown_balance(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let location = AssetLocations::<T>::get(asset_id).ok_or(Error::<T>::LocationNotSet)?;
+
+			let amount = T::UnknownBalances::take(&who, &location);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			T::Currency::deposit(asset_id, &who, amount)?;
+
+			Self::deposit_event(Event::UnknownBalanceClaimed(asset_id, who, amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Existential deposit of given asset.
+	pub fn existential_deposit(asset_id: T::AssetId) -> T::Balance {
+		if asset_id == T::NativeAssetId::get() {
+			return T::NativeExistentialDeposit::get();
+		}
+
+		Assets::<T>::get(asset_id)
+			.map(|details| details.existential_deposit)
+			.unwrap_or_else(T::DefaultExistentialDeposit::get)
+	}
+
+	fn update_details(
+		asset_id: T::AssetId,
+		f: impl FnOnce(&mut AssetDetails<T::Balance>) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetDetailsFixed);
+
+		Assets::<T>::try_mutate(asset_id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+			f(details)
+		})
+	}
+}
@@ -0,0 +1,210 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_asset_details;
+use crate::{Config, UnknownBalances};
+use frame_support::parameter_types;
+use frame_support::traits::{Contains, GenesisBuild};
+use frame_system::EnsureRoot;
+use orml_traits::GetByKey;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Amount = i128;
+pub type Balance = u128;
+pub type AssetLocation = u32;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const RESERVED_LOCATION: AssetLocation = 1;
+
+pub const BSX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const ETH: AssetId = 2;
+
+pub const NATIVE_EXISTENTIAL_DEPOSIT: Balance = 1_000;
+pub const DEFAULT_EXISTENTIAL_DEPOSIT: Balance = 100;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
+		AssetDetails: pallet_asset_details::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxLocks: u32 = 50;
+	pub const NativeAssetId: AssetId = BSX;
+	pub const NativeExistentialDeposit: Balance = NATIVE_EXISTENTIAL_DEPOSIT;
+	pub const DefaultExistentialDeposit: Balance = DEFAULT_EXISTENTIAL_DEPOSIT;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+impl pallet_asset_registry::Config for Test {
+	type AssetId = AssetId;
+}
+
+pub struct ExistentialDeposits;
+impl GetByKey<AssetId, Balance> for ExistentialDeposits {
+	fn get(currency_id: &AssetId) -> Balance {
+		AssetDetails::existential_deposit(*currency_id)
+	}
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+}
+
+pub struct ReservedLocations;
+impl Contains<AssetLocation> for ReservedLocations {
+	fn contains(location: &AssetLocation) -> bool {
+		*location == RESERVED_LOCATION
+	}
+}
+
+thread_local! {
+	static UNKNOWN_BALANCES: RefCell<HashMap<(AccountId, AssetLocation), Balance>> = RefCell::new(HashMap::new());
+}
+
+/// Holds balances of unknown assets in memory.
+pub struct UnknownTokens;
+impl UnknownTokens {
+	pub fn deposit(who: AccountId, location: AssetLocation, amount: Balance) {
+		UNKNOWN_BALANCES.with(|balances| *balances.borrow_mut().entry((who, location)).or_default() += amount);
+	}
+
+	pub fn balance(who: AccountId, location: AssetLocation) -> Balance {
+		UNKNOWN_BALANCES.with(|balances| balances.borrow().get(&(who, location)).copied().unwrap_or_default())
+	}
+}
+
+impl UnknownBalances<AccountId, AssetLocation, Balance> for UnknownTokens {
+	fn take(who: &AccountId, location: &AssetLocation) -> Balance {
+		UNKNOWN_BALANCES.with(|balances| balances.borrow_mut().remove(&(*who, *location)).unwrap_or_default())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn put(who: &AccountId, location: &AssetLocation, amount: Balance) {
+		Self::deposit(*who, *location, amount);
+	}
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type NativeAssetId = NativeAssetId;
+	type NativeExistentialDeposit = NativeExistentialDeposit;
+	type DefaultExistentialDeposit = DefaultExistentialDeposit;
+	type AssetLocation = AssetLocation;
+	type ReservedLocations = ReservedLocations;
+	type Currency = Tokens;
+	type UnknownBalances = UnknownTokens;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	existential_deposits: Vec<(AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			existential_deposits: vec![(DOT, 10)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		UNKNOWN_BALANCES.with(|balances| balances.borrow_mut().clear());
+
+		pallet_asset_registry::GenesisConfig::<Test> {
+			core_asset_id: BSX,
+			asset_ids: vec![(b"DOT".to_vec(), DOT), (b"ETH".to_vec(), ETH)],
+			next_asset_id: 3,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_asset_details::GenesisConfig::<Test> {
+			existential_deposits: self.existential_deposits,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,277 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+#[test]
+fn existential_deposit_of_native_asset_should_be_native_existential_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(AssetDetails::existential_deposit(BSX), NATIVE_EXISTENTIAL_DEPOSIT);
+	});
+}
+
+#[test]
+fn existential_deposit_should_be_set_from_genesis() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(AssetDetails::existential_deposit(DOT), 10);
+		assert_eq!(
+			AssetDetails::asset_details(DOT),
+			Some(crate::AssetDetails {
+				existential_deposit: 10
+			})
+		);
+	});
+}
+
+#[test]
+fn existential_deposit_should_fall_back_to_default_for_assets_without_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(AssetDetails::asset_details(ETH), None);
+		assert_eq!(AssetDetails::existential_deposit(ETH), DEFAULT_EXISTENTIAL_DEPOSIT);
+
+		// not even created in the registry
+		assert_eq!(AssetDetails::existential_deposit(1_000), DEFAULT_EXISTENTIAL_DEPOSIT);
+	});
+}
+
+#[test]
+fn register_should_create_asset_with_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::register(Origin::root(), b"KSM".to_vec(), 50));
+
+		let asset_id = AssetRegistry::get_or_create_asset(b"KSM".to_vec()).unwrap();
+		assert_eq!(AssetDetails::existential_deposit(asset_id), 50);
+
+		expect_events(vec![Event::pallet_asset_details(crate::Event::Registered(
+			asset_id,
+			b"KSM".to_vec(),
+			50,
+		))]);
+	});
+}
+
+#[test]
+fn register_should_add_details_to_asset_existing_in_registry() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::register(Origin::root(), b"ETH".to_vec(), 50));
+
+		assert_eq!(AssetDetails::existential_deposit(ETH), 50);
+
+		expect_events(vec![Event::pallet_asset_details(crate::Event::Registered(
+			ETH,
+			b"ETH".to_vec(),
+			50,
+		))]);
+	});
+}
+
+#[test]
+fn register_should_fail_when_asset_has_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::register(Origin::root(), b"DOT".to_vec(), 50),
+			Error::AssetAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_should_fail_when_origin_is_not_registry_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::register(Origin::signed(ALICE), b"KSM".to_vec(), 50),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_update_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::set_existential_deposit(Origin::root(), DOT, 20));
+
+		assert_eq!(AssetDetails::existential_deposit(DOT), 20);
+
+		expect_events(vec![Event::pallet_asset_details(
+			crate::Event::ExistentialDepositUpdated(DOT, 20),
+		)]);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_fail_when_origin_is_not_registry_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_existential_deposit(Origin::signed(ALICE), DOT, 20),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_fail_for_native_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_existential_deposit(Origin::root(), BSX, 20),
+			Error::NativeAssetDetailsFixed
+		);
+	});
+}
+
+#[test]
+fn set_existential_deposit_should_fail_when_asset_has_no_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_existential_deposit(Origin::root(), ETH, 20),
+			Error::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn set_location_should_map_location_to_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+
+		assert_eq!(AssetDetails::asset_location(DOT), Some(100));
+		assert_eq!(AssetDetails::location_asset(100), Some(DOT));
+
+		expect_events(vec![Event::pallet_asset_details(crate::Event::LocationSet(DOT, 100))]);
+	});
+}
+
+#[test]
+fn set_location_should_replace_previous_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 200));
+
+		assert_eq!(AssetDetails::asset_location(DOT), Some(200));
+		assert_eq!(AssetDetails::location_asset(100), None);
+		assert_eq!(AssetDetails::location_asset(200), Some(DOT));
+	});
+}
+
+#[test]
+fn set_location_should_do_nothing_when_location_is_already_set_for_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+		System::reset_events();
+
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+
+		assert_eq!(AssetDetails::asset_location(DOT), Some(100));
+		assert_eq!(AssetDetails::location_asset(100), Some(DOT));
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn set_location_should_fail_for_reserved_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_location(Origin::root(), DOT, RESERVED_LOCATION),
+			Error::LocationReserved
+		);
+	});
+}
+
+#[test]
+fn set_location_should_fail_when_location_belongs_to_another_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetDetails::register(Origin::root(), b"ETH".to_vec(), 50));
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+
+		assert_noop!(
+			AssetDetails::set_location(Origin::root(), ETH, 100),
+			Error::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn set_location_should_fail_when_origin_is_not_registry_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_location(Origin::signed(ALICE), DOT, 100),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_location_should_fail_for_native_asset_or_asset_without_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetDetails::set_location(Origin::root(), BSX, 100),
+			Error::NativeAssetDetailsFixed
+		);
+		assert_noop!(
+			AssetDetails::set_location(Origin::root(), ETH, 100),
+			Error::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn claim_unknown_balance_should_deposit_balance_held_under_asset_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		UnknownTokens::deposit(ALICE, 100, 500);
+		UnknownTokens::deposit(BOB, 100, 700);
+		UnknownTokens::deposit(ALICE, 200, 900);
+		assert_ok!(AssetDetails::set_location(Origin::root(), DOT, 100));
+
+		assert_ok!(AssetDetails::claim_unknown_balance(Origin::signed(ALICE), DOT));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 500);
+		assert_eq!(UnknownTokens::balance(ALICE, 100), 0);
+		// balances of other accounts and locations are left alone
+		assert_eq!(UnknownTokens::balance(BOB, 100), 700);
+		assert_eq!(UnknownTokens::balance(ALICE, 200), 900);
+
+		expect_events(vec![Event::pallet_asset_details(
+			crate::Event::UnknownBalanceClaimed(DOT, ALICE, 500),
+		)]);
+
+		assert_noop!(
+			AssetDetails::claim_unknown_balance(Origin::signed(ALICE), DOT),
+			Error::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn claim_unknown_balance_should_fail_when_asset_has_no_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		UnknownTokens::deposit(ALICE, 100, 500);
+
+		assert_noop!(
+			AssetDetails::claim_unknown_balance(Origin::signed(ALICE), DOT),
+			Error::LocationNotSet
+		);
+		assert_noop!(
+			AssetDetails::claim_unknown_balance(Origin::root(), DOT),
+			BadOrigin
+		);
+		assert_eq!(UnknownTokens::balance(ALICE, 100), 500);
+	});
+}
@@ -0,0 +1,85 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{One, Saturating};
+
+const SEED: u32 = 1;
+
+benchmarks! {
+	where_clause { where T::CurrencyId: From<u32> }
+
+	dust_account {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to_dust: T::AccountId = account("dust", 0, SEED);
+		let currency_id: T::CurrencyId = 1u32.into();
+		let dust = T::MinCurrencyDeposits::get(&currency_id).saturating_sub(One::one());
+
+		// whitelisted while funded, so the dust is not removed right away
+		AccountWhitelist::<T>::insert(&to_dust, ());
+		T::MultiCurrency::deposit(currency_id, &to_dust, dust)?;
+		AccountWhitelist::<T>::remove(&to_dust);
+
+		let rewards = T::Reward::get().saturating_mul(T::Balance::from(10u32));
+		T::MultiCurrency::deposit(T::NativeCurrencyId::get(), &T::RewardAccount::get(), rewards)?;
+	}: _(RawOrigin::Signed(caller.clone()), to_dust.clone(), currency_id)
+	verify {
+		assert!(T::MultiCurrency::total_balance(currency_id, &to_dust).is_zero());
+		assert_eq!(T::MultiCurrency::free_balance(T::NativeCurrencyId::get(), &caller), T::Reward::get());
+	}
+
+	add_account {
+		let origin = T::WhitelistUpdateOrigin::successful_origin();
+		let to_add: T::AccountId = account("whitelisted", 0, SEED);
+		let call = Call::<T>::add_account(to_add.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(AccountWhitelist::<T>::contains_key(&to_add));
+	}
+
+	remove_account {
+		let origin = T::WhitelistUpdateOrigin::successful_origin();
+		let to_remove: T::AccountId = account("whitelisted", 0, SEED);
+		AccountWhitelist::<T>::insert(&to_remove, ());
+		let call = Call::<T>::remove_account(to_remove.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!AccountWhitelist::<T>::contains_key(&to_remove));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_dust_account::<Test>());
+			assert_ok!(test_benchmark_add_account::<Test>());
+			assert_ok!(test_benchmark_remove_account::<Test>());
+		});
+	}
+}
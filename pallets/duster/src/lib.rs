@@ -0,0 +1,261 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Duster pallet
+//!
+//! Removes balances below the existential deposit of an asset ("dust").
+//!
+//! Plugged into `orml_tokens::OnDust`, dust left on an account by a transfer is moved to
+//! `DustAccount` right away. Accounts which are below the existential deposit for another reason
+//! (e.g. the existential deposit was raised) can be reported by anyone with `dust_account`. The
+//! dust is moved to `DustAccount` and the reporter is paid `Reward` in native currency from
+//! `RewardAccount`.
+//!
+//! Whitelisted accounts are never dusted. The whitelist is managed by `WhitelistUpdateOrigin`
+//! and extended by `ProtectedAccounts` of the runtime, such as XYK pool accounts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use orml_traits::{GetByKey, MultiCurrency, OnDust};
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Accounts which must never be dusted.
+pub trait DustRemovalWhitelist<AccountId> {
+	fn contains(who: &AccountId) -> bool;
+}
+
+impl<AccountId> DustRemovalWhitelist<AccountId> for () {
+	fn contains(_who: &AccountId) -> bool {
+		false
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::AtLeast32BitUnsigned;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Balance type.
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize;
+
+		/// Asset type.
+		type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord;
+
+		/// Currency for dust transfers and rewards.
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Self::Balance>;
+
+		/// Existential deposit of each asset.
+		type MinCurrencyDeposits: GetByKey<Self::CurrencyId, Self::Balance>;
+
+		/// Native currency id, in which the reward is paid.
+		type NativeCurrencyId: Get<Self::CurrencyId>;
+
+		/// Reward paid to the reporter of a dust account.
+		type Reward: Get<Self::Balance>;
+
+		/// Account receiving the dust.
+		type DustAccount: Get<Self::AccountId>;
+
+		/// Account paying the rewards.
+		type RewardAccount: Get<Self::AccountId>;
+
+		/// Accounts protected by the runtime in addition to the whitelist, e.g. pool accounts.
+		type ProtectedAccounts: DustRemovalWhitelist<Self::AccountId>;
+
+		/// Origin which may update the whitelist.
+		type WhitelistUpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account is whitelisted and cannot be dusted.
+		AccountWhitelisted,
+		/// Account is not whitelisted.
+		AccountNotWhitelisted,
+		/// Account has no balance of the asset.
+		ZeroBalance,
+		/// Balance of the account is not below the existential deposit.
+		BalanceSufficient,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId", T::CurrencyId = "CurrencyId", T::Balance = "Balance")]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Dust was removed from an account. \[who, currency id, amount\]
+		Dusted(T::AccountId, T::CurrencyId, T::Balance),
+
+		/// Account was added to the whitelist. \[who\]
+		Added(T::AccountId),
+
+		/// Account was removed from the whitelist. \[who\]
+		Removed(T::AccountId),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted)]
+	/// Accounts which are never dusted.
+	pub type AccountWhitelist<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub account_whitelist: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig {
+				account_whitelist: vec![],
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for account in self.account_whitelist.iter() {
+				AccountWhitelist::<T>::insert(account, ());
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dust `account` of `currency_id` if its balance is below the existential deposit.
+		///
+		/// The dust is moved to `DustAccount` and the caller is rewarded.
+		///
+		/// Emits `Dusted` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::dust_account())]
+		#[transactional]
+		pub fn dust_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			currency_id: T::CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Self::is_whitelisted(&account), Error::<T>::AccountWhitelisted);
+
+			let dust = T::MultiCurrency::total_balance(currency_id, &account);
+
+			ensure!(!dust.is_zero(), Error::<T>::ZeroBalance);
+			ensure!(
+				dust < T::MinCurrencyDeposits::get(&currency_id),
+				Error::<T>::BalanceSufficient
+			);
+
+			Self::transfer_dust(&account, currency_id, dust)?;
+
+			T::MultiCurrency::transfer(T::NativeCurrencyId::get(), &T::RewardAccount::get(), &who, T::Reward::get())?;
+
+			Ok(().into())
+		}
+
+		/// Add `account` to the whitelist.
+		///
+		/// Emits `Added` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::add_account())]
+		pub fn add_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+			T::WhitelistUpdateOrigin::ensure_origin(origin)?;
+
+			AccountWhitelist::<T>::insert(&account, ());
+
+			Self::deposit_event(Event::Added(account));
+
+			Ok(().into())
+		}
+
+		/// Remove `account` from the whitelist.
+		///
+		/// Emits `Removed` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_account())]
+		pub fn remove_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+			T::WhitelistUpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AccountWhitelist::<T>::contains_key(&account),
+				Error::<T>::AccountNotWhitelisted
+			);
+
+			AccountWhitelist::<T>::remove(&account);
+
+			Self::deposit_event(Event::Removed(account));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `who` is protected from dusting.
+	pub fn is_whitelisted(who: &T::AccountId) -> bool {
+		AccountWhitelist::<T>::contains_key(who) || T::ProtectedAccounts::contains(who)
+	}
+
+	fn transfer_dust(who: &T::AccountId, currency_id: T::CurrencyId, dust: T::Balance) -> DispatchResult {
+		T::MultiCurrency::transfer(currency_id, who, &T::DustAccount::get(), dust)?;
+
+		Self::deposit_event(Event::Dusted(who.clone(), currency_id, dust));
+
+		Ok(())
+	}
+}
+
+impl<T: Config> OnDust<T::AccountId, T::CurrencyId, T::Balance> for Pallet<T> {
+	fn on_dust(who: &T::AccountId, currency_id: T::CurrencyId, amount: T::Balance) {
+		if Self::is_whitelisted(who) {
+			return;
+		}
+
+		// Failing to move the dust only leaves it on the account, where it can be reported later.
+		let _ = Self::transfer_dust(who, currency_id, amount);
+	}
+}
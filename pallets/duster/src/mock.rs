@@ -0,0 +1,204 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_duster;
+use crate::{Config, DustRemovalWhitelist};
+use frame_support::parameter_types;
+use frame_support::traits::GenesisBuild;
+use frame_system::EnsureRoot;
+use orml_traits::GetByKey;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+pub type Amount = i128;
+pub type Balance = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const DUSTER: AccountId = 3;
+pub const POOL: AccountId = 42;
+pub const TREASURY: AccountId = 100;
+
+pub const BSX: AssetId = 0;
+pub const DOT: AssetId = 1;
+
+pub const REWARD: Balance = 50;
+
+thread_local! {
+	static DOT_EXISTENTIAL_DEPOSIT: RefCell<Balance> = RefCell::new(1_000);
+}
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
+		Duster: pallet_duster::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxLocks: u32 = 50;
+	pub const NativeCurrencyId: AssetId = BSX;
+	pub const Reward: Balance = REWARD;
+	pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+pub struct ExistentialDeposits;
+impl GetByKey<AssetId, Balance> for ExistentialDeposits {
+	fn get(currency_id: &AssetId) -> Balance {
+		match *currency_id {
+			BSX => 1,
+			_ => DOT_EXISTENTIAL_DEPOSIT.with(|v| *v.borrow()),
+		}
+	}
+}
+
+/// Raise the existential deposit of DOT, leaving existing accounts below it.
+pub fn set_dot_existential_deposit(existential_deposit: Balance) {
+	DOT_EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = existential_deposit);
+}
+
+pub struct ProtectedAccounts;
+impl DustRemovalWhitelist<AccountId> for ProtectedAccounts {
+	fn contains(who: &AccountId) -> bool {
+		*who == POOL
+	}
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = Duster;
+	type MaxLocks = MaxLocks;
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type CurrencyId = AssetId;
+	type MultiCurrency = Tokens;
+	type MinCurrencyDeposits = ExistentialDeposits;
+	type NativeCurrencyId = NativeCurrencyId;
+	type Reward = Reward;
+	type DustAccount = TreasuryAccount;
+	type RewardAccount = TreasuryAccount;
+	type ProtectedAccounts = ProtectedAccounts;
+	type WhitelistUpdateOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	account_whitelist: Vec<AccountId>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, DOT, 5_000),
+				(BOB, DOT, 1_500),
+				(POOL, DOT, 1_500),
+				(TREASURY, BSX, 1_000),
+			],
+			account_whitelist: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_whitelisted_account(mut self, account: AccountId) -> Self {
+		self.account_whitelist.push(account);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		set_dot_existential_deposit(1_000);
+
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_duster::GenesisConfig::<Test> {
+			account_whitelist: self.account_whitelist,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,180 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+#[test]
+fn dust_account_should_move_dust_and_reward_reporter() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_dot_existential_deposit(2_000);
+
+		assert_ok!(Duster::dust_account(Origin::signed(DUSTER), BOB, DOT));
+
+		assert_eq!(Tokens::total_balance(DOT, &BOB), 0);
+		assert_eq!(Tokens::total_balance(DOT, &TREASURY), 1_500);
+		assert_eq!(Tokens::free_balance(BSX, &DUSTER), REWARD);
+		assert_eq!(Tokens::free_balance(BSX, &TREASURY), 1_000 - REWARD);
+
+		expect_events(vec![Event::pallet_duster(crate::Event::Dusted(BOB, DOT, 1_500))]);
+	});
+}
+
+#[test]
+fn dust_account_should_fail_when_balance_is_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(DUSTER), ALICE, DOT),
+			Error::BalanceSufficient
+		);
+	});
+}
+
+#[test]
+fn dust_account_should_fail_when_account_has_no_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Duster::dust_account(Origin::signed(DUSTER), DUSTER, DOT),
+			Error::ZeroBalance
+		);
+	});
+}
+
+#[test]
+fn dust_account_should_fail_for_whitelisted_account() {
+	ExtBuilder::default()
+		.with_whitelisted_account(BOB)
+		.build()
+		.execute_with(|| {
+			set_dot_existential_deposit(2_000);
+
+			assert_noop!(
+				Duster::dust_account(Origin::signed(DUSTER), BOB, DOT),
+				Error::AccountWhitelisted
+			);
+		});
+}
+
+#[test]
+fn dust_account_should_fail_for_protected_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_dot_existential_deposit(2_000);
+
+		assert_noop!(
+			Duster::dust_account(Origin::signed(DUSTER), POOL, DOT),
+			Error::AccountWhitelisted
+		);
+	});
+}
+
+#[test]
+fn dust_account_should_fail_for_unsigned_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_dot_existential_deposit(2_000);
+
+		assert_noop!(Duster::dust_account(Origin::none(), BOB, DOT), BadOrigin);
+	});
+}
+
+#[test]
+fn dust_account_should_not_move_dust_when_reward_cannot_be_paid() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DOT, 1_500)])
+		.build()
+		.execute_with(|| {
+			set_dot_existential_deposit(2_000);
+
+			assert_noop!(
+				Duster::dust_account(Origin::signed(DUSTER), BOB, DOT),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+
+			assert_eq!(Tokens::total_balance(DOT, &BOB), 1_500);
+		});
+}
+
+#[test]
+fn dust_left_by_transfer_should_be_moved_to_dust_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(DOT, &ALICE, &BOB, 4_500));
+
+		assert_eq!(Tokens::total_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::total_balance(DOT, &BOB), 6_000);
+		assert_eq!(Tokens::total_balance(DOT, &TREASURY), 500);
+
+		expect_events(vec![Event::pallet_duster(crate::Event::Dusted(ALICE, DOT, 500))]);
+	});
+}
+
+#[test]
+fn dust_left_by_transfer_should_stay_on_protected_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(DOT, &POOL, &BOB, 1_000));
+
+		assert_eq!(Tokens::total_balance(DOT, &POOL), 500);
+		assert_eq!(Tokens::total_balance(DOT, &TREASURY), 0);
+	});
+}
+
+#[test]
+fn add_account_should_whitelist_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Duster::add_account(Origin::root(), BOB));
+
+		assert!(Duster::is_whitelisted(&BOB));
+
+		expect_events(vec![Event::pallet_duster(crate::Event::Added(BOB))]);
+	});
+}
+
+#[test]
+fn add_account_should_fail_when_origin_is_not_whitelist_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Duster::add_account(Origin::signed(ALICE), BOB), BadOrigin);
+	});
+}
+
+#[test]
+fn remove_account_should_remove_account_from_whitelist() {
+	ExtBuilder::default()
+		.with_whitelisted_account(BOB)
+		.build()
+		.execute_with(|| {
+			assert!(Duster::is_whitelisted(&BOB));
+
+			assert_ok!(Duster::remove_account(Origin::root(), BOB));
+
+			assert!(!Duster::is_whitelisted(&BOB));
+
+			expect_events(vec![Event::pallet_duster(crate::Event::Removed(BOB))]);
+		});
+}
+
+#[test]
+fn remove_account_should_fail_when_account_is_not_whitelisted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Duster::remove_account(Origin::root(), BOB),
+			Error::AccountNotWhitelisted
+		);
+		assert_noop!(Duster::remove_account(Origin::signed(ALICE), BOB), BadOrigin);
+	});
+}
@@ -0,0 +1,76 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+
+/// A parachain other than this one.
+fn sibling<T: Config>() -> ParaId {
+	ParaId::from(u32::from(T::SelfParaId::get()) + 1)
+}
+
+benchmarks! {
+	open_channel {
+		let origin = T::UpdateOrigin::successful_origin();
+		let recipient = sibling::<T>();
+		let call = Call::<T>::open_channel(recipient, 8, 1024);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let channel = (T::SelfParaId::get(), recipient);
+		assert_eq!(Pallet::<T>::last_request(channel), Some(ChannelRequest::OpenRequested));
+	}
+
+	accept_channel {
+		let origin = T::UpdateOrigin::successful_origin();
+		let sender = sibling::<T>();
+		let call = Call::<T>::accept_channel(sender);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let channel = (sender, T::SelfParaId::get());
+		assert_eq!(Pallet::<T>::last_request(channel), Some(ChannelRequest::Accepted));
+	}
+
+	close_channel {
+		let origin = T::UpdateOrigin::successful_origin();
+		let channel = (T::SelfParaId::get(), sibling::<T>());
+		LastRequest::<T>::insert(channel, ChannelRequest::OpenRequested);
+		let call = Call::<T>::close_channel(channel.0, channel.1);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Pallet::<T>::last_request(channel), Some(ChannelRequest::CloseRequested));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_open_channel::<Test>());
+			assert_ok!(test_benchmark_accept_channel::<Test>());
+			assert_ok!(test_benchmark_close_channel::<Test>());
+		});
+	}
+}
@@ -0,0 +1,247 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # HRMP channels pallet
+//!
+//! Lets a privileged origin open, accept and close HRMP channels with sibling parachains.
+//!
+//! The HRMP calls of the relay chain are dispatched by the parachain itself, so each dispatchable
+//! sends an XCM to the relay chain which withdraws `RelayFee` of the relay chain token from the
+//! sovereign account of this parachain, buys execution with it, `Transact`s the HRMP call with the
+//! parachain origin and deposits the unused fee back to the sovereign account.
+//!
+//! The last request made for each channel involving this parachain is recorded. The relay chain
+//! decides whether a request succeeds and the record is not updated from the relay chain, so it
+//! does not tell whether the channel is open or closed. The channels actually open are known to
+//! `cumulus_pallet_parachain_system` from the relay chain state. Requests can always be made again,
+//! e.g. when the relay chain rejected a previous one.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use cumulus_primitives_core::ParaId;
+use frame_support::{dispatch::DispatchResult, sp_runtime::RuntimeDebug, traits::Get, weights::Weight};
+use sp_std::prelude::*;
+use xcm::v0::{Junction, MultiAsset, MultiLocation, Order, OriginKind, SendXcm, Xcm};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+/// Call indices of the HRMP pallet of the relay chain.
+const HRMP_INIT_OPEN_CHANNEL: u8 = 0;
+const HRMP_ACCEPT_OPEN_CHANNEL: u8 = 1;
+const HRMP_CLOSE_CHANNEL: u8 = 2;
+
+/// Last request made for a channel.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug)]
+pub enum ChannelRequest {
+	/// This parachain requested to open the channel.
+	OpenRequested,
+	/// This parachain accepted the request of the sender to open the channel.
+	Accepted,
+	/// This parachain requested to close the channel.
+	CloseRequested,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin which may manage the channels.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Id of this parachain.
+		type SelfParaId: Get<ParaId>;
+
+		/// Sender of the messages to the relay chain.
+		type XcmSender: SendXcm;
+
+		/// Index of the HRMP pallet in the relay chain runtime.
+		type HrmpPalletIndex: Get<u8>;
+
+		/// Amount of the relay chain token withdrawn from the sovereign account to pay the fees.
+		type RelayFee: Get<u128>;
+
+		/// Weight of the HRMP call on the relay chain.
+		type RelayTransactWeight: Get<Weight>;
+
+		/// Weight of the XCM instructions around the HRMP call on the relay chain.
+		type RelayXcmWeight: Get<Weight>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Channel does not involve this parachain.
+		NotOwnChannel,
+		/// Message could not be sent to the relay chain.
+		SendFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event {
+		/// Request to open a channel to a sibling was sent. \[recipient, max capacity, max message size\]
+		OpenChannelRequested(ParaId, u32, u32),
+
+		/// Channel from a sibling was accepted. \[sender\]
+		ChannelAccepted(ParaId),
+
+		/// Request to close a channel was sent. \[sender, recipient\]
+		CloseChannelRequested(ParaId, ParaId),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn last_request)]
+	/// Last request made for a channel, keyed by sender and recipient.
+	pub type LastRequest<T: Config> = StorageMap<_, Twox64Concat, (ParaId, ParaId), ChannelRequest, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request to open a channel from this parachain to `recipient`.
+		///
+		/// The channel is opened once `recipient` accepts it.
+		///
+		/// Emits `OpenChannelRequested` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::open_channel())]
+		pub fn open_channel(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			max_capacity: u32,
+			max_message_size: u32,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let channel = (T::SelfParaId::get(), recipient);
+
+			let call = (T::HrmpPalletIndex::get(), HRMP_INIT_OPEN_CHANNEL, recipient, max_capacity, max_message_size);
+			Self::send_to_relay(call.encode())?;
+
+			LastRequest::<T>::insert(channel, ChannelRequest::OpenRequested);
+
+			Self::deposit_event(Event::OpenChannelRequested(recipient, max_capacity, max_message_size));
+
+			Ok(().into())
+		}
+
+		/// Accept the request of `sender` to open a channel to this parachain.
+		///
+		/// Emits `ChannelAccepted` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::accept_channel())]
+		pub fn accept_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let channel = (sender, T::SelfParaId::get());
+
+			let call = (T::HrmpPalletIndex::get(), HRMP_ACCEPT_OPEN_CHANNEL, sender);
+			Self::send_to_relay(call.encode())?;
+
+			LastRequest::<T>::insert(channel, ChannelRequest::Accepted);
+
+			Self::deposit_event(Event::ChannelAccepted(sender));
+
+			Ok(().into())
+		}
+
+		/// Request to close the channel from `sender` to `recipient`.
+		///
+		/// Either of them must be this parachain.
+		///
+		/// Emits `CloseChannelRequested` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::close_channel())]
+		pub fn close_channel(origin: OriginFor<T>, sender: ParaId, recipient: ParaId) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let self_para_id = T::SelfParaId::get();
+			ensure!(sender == self_para_id || recipient == self_para_id, Error::<T>::NotOwnChannel);
+
+			let channel = (sender, recipient);
+
+			// `HrmpChannelId` is encoded as the sender followed by the recipient.
+			let call = (T::HrmpPalletIndex::get(), HRMP_CLOSE_CHANNEL, sender, recipient);
+			Self::send_to_relay(call.encode())?;
+
+			LastRequest::<T>::insert(channel, ChannelRequest::CloseRequested);
+
+			Self::deposit_event(Event::CloseChannelRequested(sender, recipient));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Send a message to the relay chain which pays for and dispatches the encoded `call` with the
+	/// parachain origin.
+	fn send_to_relay(call: Vec<u8>) -> DispatchResult {
+		let transact_weight = T::RelayTransactWeight::get();
+		// The relay chain token, as seen by the relay chain.
+		let fees = MultiAsset::ConcreteFungible {
+			id: MultiLocation::Null,
+			amount: T::RelayFee::get(),
+		};
+
+		let message = Xcm::WithdrawAsset {
+			assets: vec![fees],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight: transact_weight,
+					debt: T::RelayXcmWeight::get(),
+					halt_on_error: true,
+					xcm: vec![Xcm::Transact {
+						origin_type: OriginKind::Native,
+						require_weight_at_most: transact_weight,
+						call: call.into(),
+					}],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::Parachain(T::SelfParaId::get().into())),
+				},
+			],
+		};
+
+		T::XcmSender::send_xcm(MultiLocation::X1(Junction::Parent), message).map_err(|_| Error::<T>::SendFailed.into())
+	}
+}
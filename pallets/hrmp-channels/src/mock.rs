@@ -0,0 +1,146 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_hrmp_channels;
+use crate::Config;
+use cumulus_primitives_core::ParaId;
+use frame_support::{parameter_types, weights::Weight};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use xcm::v0::{Error as XcmError, MultiLocation, Result as XcmResult, SendXcm, Xcm};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+
+pub const SELF_PARA_ID: u32 = 100;
+pub const SIBLING: u32 = 2000;
+pub const OTHER_SIBLING: u32 = 3000;
+
+pub const HRMP_PALLET_INDEX: u8 = 60;
+pub const RELAY_FEE: u128 = 1_000;
+pub const RELAY_TRANSACT_WEIGHT: Weight = 1_000_000;
+pub const RELAY_XCM_WEIGHT: Weight = 2_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		HrmpChannels: pallet_hrmp_channels::{Pallet, Call, Storage, Event},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub SelfParaId: ParaId = SELF_PARA_ID.into();
+	pub const HrmpPalletIndex: u8 = HRMP_PALLET_INDEX;
+	pub const RelayFee: u128 = RELAY_FEE;
+	pub const RelayTransactWeight: Weight = RELAY_TRANSACT_WEIGHT;
+	pub const RelayXcmWeight: Weight = RELAY_XCM_WEIGHT;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+thread_local! {
+	static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+	static SEND_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+/// Messages sent by the pallet.
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+/// Makes the following messages fail to send.
+pub fn fail_sending() {
+	SEND_FAILS.with(|fails| *fails.borrow_mut() = true);
+}
+
+/// Records sent messages.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	fn send_xcm(dest: MultiLocation, msg: Xcm<()>) -> XcmResult {
+		if SEND_FAILS.with(|fails| *fails.borrow()) {
+			return Err(XcmError::Unroutable);
+		}
+		SENT_XCM.with(|sent| sent.borrow_mut().push((dest, msg)));
+		Ok(())
+	}
+}
+
+impl Config for Test {
+	type Event = Event;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type SelfParaId = SelfParaId;
+	type XcmSender = TestSendXcm;
+	type HrmpPalletIndex = HrmpPalletIndex;
+	type RelayFee = RelayFee;
+	type RelayTransactWeight = RelayTransactWeight;
+	type RelayXcmWeight = RelayXcmWeight;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	SENT_XCM.with(|sent| sent.borrow_mut().clear());
+	SEND_FAILS.with(|fails| *fails.borrow_mut() = false);
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
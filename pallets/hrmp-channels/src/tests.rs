@@ -0,0 +1,210 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+fn para(id: u32) -> ParaId {
+	ParaId::from(id)
+}
+
+/// Encoded relay chain call `Transact`ed by the last sent message.
+fn sent_call() -> Vec<u8> {
+	match sent_xcm().last() {
+		Some((_, Xcm::WithdrawAsset { effects, .. })) => match effects.first() {
+			Some(Order::BuyExecution { xcm, .. }) => match xcm.first() {
+				Some(Xcm::Transact { call, .. }) => call.clone().into(),
+				_ => panic!("message does not transact a call"),
+			},
+			_ => panic!("message does not buy execution"),
+		},
+		_ => panic!("no message was sent"),
+	}
+}
+
+#[test]
+fn open_channel_should_send_paid_hrmp_call_to_relay_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channel(Origin::root(), para(SIBLING), 8, 1024));
+
+		let call = (HRMP_PALLET_INDEX, 0u8, para(SIBLING), 8u32, 1024u32).encode();
+		let fees = MultiAsset::ConcreteFungible {
+			id: MultiLocation::Null,
+			amount: RELAY_FEE,
+		};
+		let message = Xcm::WithdrawAsset {
+			assets: vec![fees],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight: RELAY_TRANSACT_WEIGHT,
+					debt: RELAY_XCM_WEIGHT,
+					halt_on_error: true,
+					xcm: vec![Xcm::Transact {
+						origin_type: OriginKind::Native,
+						require_weight_at_most: RELAY_TRANSACT_WEIGHT,
+						call: call.into(),
+					}],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::Parachain(SELF_PARA_ID)),
+				},
+			],
+		};
+		assert_eq!(sent_xcm(), vec![(MultiLocation::X1(Junction::Parent), message)]);
+
+		assert_eq!(
+			HrmpChannels::last_request((para(SELF_PARA_ID), para(SIBLING))),
+			Some(ChannelRequest::OpenRequested)
+		);
+
+		expect_events(vec![Event::pallet_hrmp_channels(crate::Event::OpenChannelRequested(
+			para(SIBLING),
+			8,
+			1024,
+		))]);
+	});
+}
+
+#[test]
+fn open_channel_should_be_requested_again_while_request_is_pending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channel(Origin::root(), para(SIBLING), 8, 1024));
+		assert_ok!(HrmpChannels::open_channel(Origin::root(), para(SIBLING), 4, 512));
+
+		assert_eq!(sent_xcm().len(), 2);
+		assert_eq!(
+			sent_call(),
+			(HRMP_PALLET_INDEX, 0u8, para(SIBLING), 4u32, 512u32).encode()
+		);
+		assert_eq!(
+			HrmpChannels::last_request((para(SELF_PARA_ID), para(SIBLING))),
+			Some(ChannelRequest::OpenRequested)
+		);
+	});
+}
+
+#[test]
+fn accept_channel_should_send_hrmp_call_to_relay_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::accept_channel(Origin::root(), para(SIBLING)));
+
+		assert_eq!(sent_call(), (HRMP_PALLET_INDEX, 1u8, para(SIBLING)).encode());
+		assert_eq!(
+			HrmpChannels::last_request((para(SIBLING), para(SELF_PARA_ID))),
+			Some(ChannelRequest::Accepted)
+		);
+
+		expect_events(vec![Event::pallet_hrmp_channels(crate::Event::ChannelAccepted(para(
+			SIBLING,
+		)))]);
+
+		// accepting again is not rejected
+		assert_ok!(HrmpChannels::accept_channel(Origin::root(), para(SIBLING)));
+		assert_eq!(sent_xcm().len(), 2);
+	});
+}
+
+#[test]
+fn close_channel_should_send_hrmp_call_for_channels_of_this_parachain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpChannels::open_channel(Origin::root(), para(SIBLING), 8, 1024));
+		assert_ok!(HrmpChannels::close_channel(
+			Origin::root(),
+			para(SELF_PARA_ID),
+			para(SIBLING)
+		));
+
+		assert_eq!(
+			sent_call(),
+			(HRMP_PALLET_INDEX, 2u8, para(SELF_PARA_ID), para(SIBLING)).encode()
+		);
+		assert_eq!(
+			HrmpChannels::last_request((para(SELF_PARA_ID), para(SIBLING))),
+			Some(ChannelRequest::CloseRequested)
+		);
+
+		assert_ok!(HrmpChannels::close_channel(
+			Origin::root(),
+			para(SIBLING),
+			para(SELF_PARA_ID)
+		));
+		assert_eq!(
+			HrmpChannels::last_request((para(SIBLING), para(SELF_PARA_ID))),
+			Some(ChannelRequest::CloseRequested)
+		);
+
+		expect_events(vec![
+			Event::pallet_hrmp_channels(crate::Event::CloseChannelRequested(para(SELF_PARA_ID), para(SIBLING))),
+			Event::pallet_hrmp_channels(crate::Event::CloseChannelRequested(para(SIBLING), para(SELF_PARA_ID))),
+		]);
+	});
+}
+
+#[test]
+fn close_channel_should_fail_for_channel_of_other_parachains() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpChannels::close_channel(Origin::root(), para(SIBLING), para(OTHER_SIBLING)),
+			Error::NotOwnChannel
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn requests_should_fail_when_message_can_not_be_sent() {
+	new_test_ext().execute_with(|| {
+		fail_sending();
+
+		assert_noop!(
+			HrmpChannels::open_channel(Origin::root(), para(SIBLING), 8, 1024),
+			Error::SendFailed
+		);
+		assert_noop!(
+			HrmpChannels::accept_channel(Origin::root(), para(SIBLING)),
+			Error::SendFailed
+		);
+		assert_noop!(
+			HrmpChannels::close_channel(Origin::root(), para(SELF_PARA_ID), para(SIBLING)),
+			Error::SendFailed
+		);
+	});
+}
+
+#[test]
+fn requests_should_fail_when_origin_is_not_update_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpChannels::open_channel(Origin::signed(ALICE), para(SIBLING), 8, 1024),
+			BadOrigin
+		);
+		assert_noop!(
+			HrmpChannels::accept_channel(Origin::signed(ALICE), para(SIBLING)),
+			BadOrigin
+		);
+		assert_noop!(
+			HrmpChannels::close_channel(Origin::signed(ALICE), para(SELF_PARA_ID), para(SIBLING)),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,55 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 1;
+
+const AMOUNT: Balance = 1_000_000_000_000;
+
+benchmarks! {
+	withdraw {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let asset_id = T::RelayChainAssetId::get();
+		T::Currency::deposit(asset_id, &caller, 10 * AMOUNT)?;
+		let call = Call::<T>::withdraw(AMOUNT, dest);
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())? }
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_id, &caller), 9 * AMOUNT);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_withdraw::<Test>());
+		});
+	}
+}
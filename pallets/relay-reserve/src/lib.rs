@@ -0,0 +1,171 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Relay reserve pallet
+//!
+//! Lets accounts withdraw the relay chain token back to an account on the relay chain.
+//!
+//! The relay chain is the reserve of its token. Tokens deposited to this chain by the relay chain
+//! are held in the sovereign account of this parachain on the relay chain and minted as
+//! `RelayChainAssetId` by the XCM executor. `withdraw` burns the asset and sends an XCM to the relay
+//! chain which withdraws the same amount from the sovereign account, pays the relay chain fees with
+//! it and deposits the rest to the given relay chain account.
+//!
+//! `orml_xtokens` can transfer the relay chain token to the relay chain too, but it executes the
+//! reserve withdrawal with the local XCM executor, charging the local XCM weight, and leaves the
+//! weight bought on the relay chain to the caller. `withdraw` takes only the amount and the relay
+//! chain account, burns the asset directly and buys the `RelayXcmWeight` set by the runtime, so the
+//! relay chain fees do not depend on what the caller passes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::DispatchResult, traits::Get, transactional, weights::Weight};
+use orml_traits::MultiCurrency;
+use primitives::{AssetId, Balance};
+use sp_runtime::traits::Convert;
+use sp_std::prelude::*;
+use xcm::v0::{Junction, MultiAsset, MultiLocation, NetworkId, Order, SendXcm, Xcm};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Zero;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Multi currency holding the relay chain token.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Asset id of the relay chain token.
+		type RelayChainAssetId: Get<AssetId>;
+
+		/// Converts an account into the `AccountId32` bytes of the relay chain account.
+		type AccountIdConvert: Convert<Self::AccountId, [u8; 32]>;
+
+		/// Sender of the messages to the relay chain.
+		type XcmSender: SendXcm;
+
+		/// Weight of the XCM on the relay chain, paid from the withdrawn amount.
+		type RelayXcmWeight: Get<Weight>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Withdrawn amount is zero.
+		ZeroAmount,
+		/// Message could not be sent to the relay chain.
+		SendFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Relay chain token was withdrawn to the relay chain. \[who, relay chain account, amount\]
+		Withdrawn(T::AccountId, T::AccountId, Balance),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Withdraw `amount` of the relay chain token to `dest` account on the relay chain.
+		///
+		/// Relay chain fees are paid from `amount`, `dest` receives the rest. If `amount` does not
+		/// cover the fees, nothing is deposited on the relay chain.
+		///
+		/// The asset is burnt before the message is sent and the burn is reverted if the message
+		/// can not be sent.
+		///
+		/// Emits `Withdrawn` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, amount: Balance, dest: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::withdraw(T::RelayChainAssetId::get(), &who, amount)?;
+
+			Self::send_to_relay(amount, T::AccountIdConvert::convert(dest.clone()))?;
+
+			Self::deposit_event(Event::Withdrawn(who, dest, amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Send a message to the relay chain which transfers `amount` of the relay chain token from the
+	/// sovereign account of this parachain to `dest`.
+	fn send_to_relay(amount: Balance, dest: [u8; 32]) -> DispatchResult {
+		// The relay chain token, as seen by the relay chain.
+		let asset = MultiAsset::ConcreteFungible {
+			id: MultiLocation::Null,
+			amount,
+		};
+
+		let message = Xcm::WithdrawAsset {
+			assets: vec![asset],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight: 0,
+					debt: T::RelayXcmWeight::get(),
+					halt_on_error: false,
+					xcm: vec![],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: dest,
+					}),
+				},
+			],
+		};
+
+		T::XcmSender::send_xcm(MultiLocation::X1(Junction::Parent), message).map_err(|_| Error::<T>::SendFailed.into())
+	}
+}
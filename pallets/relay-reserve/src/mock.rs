@@ -0,0 +1,192 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_relay_reserve;
+use crate::Config;
+use frame_support::{parameter_types, traits::GenesisBuild, weights::Weight};
+use orml_traits::GetByKey;
+use primitives::{Amount, AssetId, Balance};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+};
+use std::cell::RefCell;
+use xcm::v0::{Error as XcmError, MultiLocation, Result as XcmResult, SendXcm, Xcm};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const DOT: AssetId = 1;
+pub const ETH: AssetId = 2;
+
+pub const RELAY_XCM_WEIGHT: Weight = 1_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
+		RelayReserve: pallet_relay_reserve::{Pallet, Call, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxLocks: u32 = 50;
+	pub const RelayChainAssetId: AssetId = DOT;
+	pub const RelayXcmWeight: Weight = RELAY_XCM_WEIGHT;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+pub struct ExistentialDeposits;
+impl GetByKey<AssetId, Balance> for ExistentialDeposits {
+	fn get(_currency_id: &AssetId) -> Balance {
+		1
+	}
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+}
+
+/// Relay chain account of an account, the account id followed by zeros.
+pub fn relay_account(who: AccountId) -> [u8; 32] {
+	let mut id = [0u8; 32];
+	id[..8].copy_from_slice(&who.to_le_bytes());
+	id
+}
+
+pub struct AccountIdConvert;
+impl Convert<AccountId, [u8; 32]> for AccountIdConvert {
+	fn convert(who: AccountId) -> [u8; 32] {
+		relay_account(who)
+	}
+}
+
+thread_local! {
+	static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+	static SEND_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+/// Messages sent by the pallet.
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+/// Makes the following messages fail to send.
+pub fn fail_sending() {
+	SEND_FAILS.with(|fails| *fails.borrow_mut() = true);
+}
+
+/// Records sent messages.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	fn send_xcm(dest: MultiLocation, msg: Xcm<()>) -> XcmResult {
+		if SEND_FAILS.with(|fails| *fails.borrow()) {
+			return Err(XcmError::Unroutable);
+		}
+		SENT_XCM.with(|sent| sent.borrow_mut().push((dest, msg)));
+		Ok(())
+	}
+}
+
+impl Config for Test {
+	type Event = Event;
+	type Currency = Tokens;
+	type RelayChainAssetId = RelayChainAssetId;
+	type AccountIdConvert = AccountIdConvert;
+	type XcmSender = TestSendXcm;
+	type RelayXcmWeight = RelayXcmWeight;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![(ALICE, DOT, 1_000), (ALICE, ETH, 1_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		SENT_XCM.with(|sent| sent.borrow_mut().clear());
+		SEND_FAILS.with(|fails| *fails.borrow_mut() = false);
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
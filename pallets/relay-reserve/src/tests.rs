@@ -0,0 +1,116 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+#[test]
+fn withdraw_should_burn_asset_and_send_it_to_relay_chain_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RelayReserve::withdraw(Origin::signed(ALICE), 400, BOB));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 600);
+		assert_eq!(Tokens::total_issuance(DOT), 600);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 0);
+
+		let message = Xcm::WithdrawAsset {
+			assets: vec![MultiAsset::ConcreteFungible {
+				id: MultiLocation::Null,
+				amount: 400,
+			}],
+			effects: vec![
+				Order::BuyExecution {
+					fees: MultiAsset::All,
+					weight: 0,
+					debt: RELAY_XCM_WEIGHT,
+					halt_on_error: false,
+					xcm: vec![],
+				},
+				Order::DepositAsset {
+					assets: vec![MultiAsset::All],
+					dest: MultiLocation::X1(Junction::AccountId32 {
+						network: NetworkId::Any,
+						id: relay_account(BOB),
+					}),
+				},
+			],
+		};
+		assert_eq!(sent_xcm(), vec![(MultiLocation::X1(Junction::Parent), message)]);
+
+		expect_events(vec![Event::pallet_relay_reserve(crate::Event::Withdrawn(
+			ALICE, BOB, 400,
+		))]);
+	});
+}
+
+#[test]
+fn withdraw_should_only_burn_relay_chain_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RelayReserve::withdraw(Origin::signed(ALICE), 1_000, ALICE));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(ETH, &ALICE), 1_000);
+	});
+}
+
+#[test]
+fn withdraw_should_fail_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(RelayReserve::withdraw(Origin::signed(ALICE), 0, BOB), Error::ZeroAmount);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn withdraw_should_fail_when_balance_is_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			RelayReserve::withdraw(Origin::signed(ALICE), 1_001, BOB),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		assert_noop!(
+			RelayReserve::withdraw(Origin::signed(BOB), 1, ALICE),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn withdraw_should_revert_burn_when_message_can_not_be_sent() {
+	ExtBuilder::default().build().execute_with(|| {
+		fail_sending();
+
+		assert_noop!(
+			RelayReserve::withdraw(Origin::signed(ALICE), 400, BOB),
+			Error::SendFailed
+		);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000);
+		assert_eq!(Tokens::total_issuance(DOT), 1_000);
+	});
+}
+
+#[test]
+fn withdraw_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(RelayReserve::withdraw(Origin::root(), 400, BOB), BadOrigin);
+	});
+}
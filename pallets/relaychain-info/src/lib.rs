@@ -0,0 +1,258 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Relay chain info pallet
+//!
+//! Records the relay parent block number and storage root of every parachain block.
+//!
+//! Parachain block times are not reliable, so pallets which need a notion of time should use the
+//! relay chain block number through the `BlockNumberProvider` implementation of this pallet.
+//! The relay parent storage root can be used to verify relay chain state proofs.
+//!
+//! ## Randomness
+//!
+//! The pallet reads the BABE epoch randomness of the relay chain from the relay chain state proof
+//! of the parachain inherent. Collators add the BABE randomness key to that proof (see
+//! `relay_babe_randomness_key`). `cumulus_pallet_parachain_system` does not keep the proof, so
+//! the inherent of this pallet carries the trie nodes of the key taken from it. The nodes are
+//! checked by `RelayChainStateProof` against the relay parent storage root the parachain system
+//! validated the block with, and the block does not contain the relay chain state twice.
+//! The randomness is exposed through `frame_support::traits::Randomness`. Unlike collective flip
+//! randomness it can not be influenced by collators, but it is known for the whole relay chain epoch.
+//!
+//! The inherent is only created when the relay chain state proof contains the BABE randomness key,
+//! otherwise the last known value is kept. Until the first value is known `random` returns the
+//! default hash, known from `BlockNumber::max_value()`. `try_random` returns `None` instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cumulus_pallet_parachain_system::{OnValidationData, RelayChainStateProof};
+use cumulus_primitives_core::{relay_chain, PersistedValidationData};
+use cumulus_primitives_parachain_inherent::ParachainInherentData;
+use frame_support::traits::{Get, Randomness};
+use sp_runtime::traits::{BlakeTwo256, BlockNumberProvider, Bounded, Hash};
+use sp_std::prelude::*;
+use sp_trie::{read_trie_value_with, Layout, Recorder, StorageProof};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: cumulus_pallet_parachain_system::Config {
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_finalize(_n: T::BlockNumber) {
+			DidUpdateRandomness::<T>::kill();
+		}
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Randomness was already updated in this block.
+		RandomnessAlreadyUpdated,
+		/// Relay chain state proof does not match the relay parent storage root.
+		InvalidRelayChainStateProof,
+		/// Relay chain state proof does not contain the BABE randomness.
+		MissingRelayRandomness,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn relay_parent_number)]
+	/// Block number of the relay parent of the current block.
+	pub type RelayParentNumber<T: Config> = StorageValue<_, relay_chain::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relay_parent_storage_root)]
+	/// Storage root of the relay parent of the current block.
+	pub type RelayParentStorageRoot<T: Config> = StorageValue<_, relay_chain::Hash, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relay_babe_randomness)]
+	/// Last known BABE epoch randomness of the relay chain and the block it was first seen in.
+	pub type RelayBabeRandomness<T: Config> = StorageValue<_, ([u8; 32], T::BlockNumber), OptionQuery>;
+
+	#[pallet::storage]
+	/// Whether randomness was updated in this block.
+	pub type DidUpdateRandomness<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the relay chain BABE randomness from the proof of the BABE randomness key.
+		///
+		/// Inherent, must be included after the validation data of the block was set. The relay
+		/// parent storage root of the validation data is the root the proof is checked against.
+		/// The proof is the part of the relay chain state proof of the parachain inherent which
+		/// proves the BABE randomness key.
+		#[pallet::weight((<T as Config>::WeightInfo::set_relay_randomness(), DispatchClass::Mandatory))]
+		pub fn set_relay_randomness(
+			origin: OriginFor<T>,
+			relay_chain_state: StorageProof,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			ensure!(!DidUpdateRandomness::<T>::get(), Error::<T>::RandomnessAlreadyUpdated);
+
+			let relay_state_proof = RelayChainStateProof::new(
+				T::SelfParaId::get(),
+				RelayParentStorageRoot::<T>::get(),
+				relay_chain_state,
+			)
+			.map_err(|_| Error::<T>::InvalidRelayChainStateProof)?;
+
+			let randomness = relay_state_proof
+				.read_optional_entry::<[u8; 32]>(&relay_babe_randomness_key())
+				.ok()
+				.flatten()
+				.ok_or(Error::<T>::MissingRelayRandomness)?;
+
+			if Self::relay_babe_randomness().map(|(known, _)| known) != Some(randomness) {
+				RelayBabeRandomness::<T>::put((randomness, frame_system::Pallet::<T>::block_number()));
+			}
+
+			DidUpdateRandomness::<T>::put(true);
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T> {
+		type Call = Call<T>;
+		type Error = sp_inherents::MakeFatalError<()>;
+		const INHERENT_IDENTIFIER: InherentIdentifier = cumulus_primitives_parachain_inherent::INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let data: ParachainInherentData = data
+				.get_data(&cumulus_primitives_parachain_inherent::INHERENT_IDENTIFIER)
+				.ok()
+				.flatten()?;
+
+			relay_randomness_proof(&data).map(Call::set_relay_randomness)
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::set_relay_randomness(..))
+		}
+	}
+}
+
+/// Storage key of the current epoch randomness in the BABE pallet of the relay chain.
+///
+/// Collators must include the key in the relay chain state proof of the parachain inherent.
+pub fn relay_babe_randomness_key() -> Vec<u8> {
+	[sp_io::hashing::twox_128(b"Babe"), sp_io::hashing::twox_128(b"Randomness")].concat()
+}
+
+/// Trie nodes of the relay chain state proof of `data` which prove the BABE randomness key.
+///
+/// `None` when the proof does not contain the key.
+fn relay_randomness_proof(data: &ParachainInherentData) -> Option<StorageProof> {
+	let root = data.validation_data.relay_parent_storage_root;
+	let db = data.relay_chain_state.clone().into_memory_db::<BlakeTwo256>();
+	let mut recorder = Recorder::<relay_chain::Hash>::new();
+
+	read_trie_value_with::<Layout<BlakeTwo256>, _, _>(&db, &root, &relay_babe_randomness_key(), &mut recorder)
+		.ok()
+		.flatten()?;
+
+	Some(StorageProof::new(
+		recorder.drain().into_iter().map(|record| record.data).collect(),
+	))
+}
+
+/// Relay chain state proof of `entries` and its storage root.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub(crate) fn relay_state_proof(entries: &[(Vec<u8>, Vec<u8>)]) -> (relay_chain::Hash, StorageProof) {
+	use sp_trie::{trie_types::TrieDBMut, MemoryDB, TrieMut};
+
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = Default::default();
+	{
+		let mut trie = TrieDBMut::<BlakeTwo256>::new(&mut db, &mut root);
+		for (key, value) in entries {
+			trie.insert(key, value).expect("entry is inserted into the trie");
+		}
+	}
+
+	let nodes = db.drain().into_iter().map(|(_, (node, _))| node).collect();
+	(root, StorageProof::new(nodes))
+}
+
+impl<T: Config> Pallet<T> {
+	/// Random value derived from the relay chain BABE epoch randomness and `subject`, and the block
+	/// in which the relay chain randomness was first seen.
+	///
+	/// `None` while no relay chain randomness is known.
+	pub fn try_random(subject: &[u8]) -> Option<(T::Hash, T::BlockNumber)> {
+		Self::relay_babe_randomness()
+			.map(|(randomness, block_number)| (T::Hashing::hash_of(&(subject, randomness)), block_number))
+	}
+}
+
+impl<T: Config> OnValidationData for Pallet<T> {
+	fn on_validation_data(data: &PersistedValidationData) {
+		RelayParentNumber::<T>::put(data.relay_parent_number);
+		RelayParentStorageRoot::<T>::put(data.relay_parent_storage_root);
+	}
+}
+
+impl<T: Config> BlockNumberProvider for Pallet<T> {
+	type BlockNumber = relay_chain::BlockNumber;
+
+	/// Block number of the relay parent of the current block.
+	///
+	/// Validation data is set by an inherent, so the value is only up to date once inherents of
+	/// the current block were applied. During `on_initialize` it is the value of the previous block.
+	fn current_block_number() -> Self::BlockNumber {
+		RelayParentNumber::<T>::get()
+	}
+}
+
+impl<T: Config> Randomness<T::Hash, T::BlockNumber> for Pallet<T> {
+	/// Random value derived from the relay chain BABE epoch randomness and `subject`.
+	///
+	/// The block number is the block in which the relay chain randomness was first seen, so the
+	/// value is not known before it. While no relay chain randomness is known the output is the
+	/// default hash and the block number is `BlockNumber::max_value()`, which callers must reject.
+	fn random(subject: &[u8]) -> (T::Hash, T::BlockNumber) {
+		Self::try_random(subject).unwrap_or_else(|| (Default::default(), T::BlockNumber::max_value()))
+	}
+}
@@ -0,0 +1,244 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, inherent::ProvideInherent, traits::Hooks};
+use sp_core::H256;
+use sp_inherents::InherentData;
+use sp_runtime::traits::{BlakeTwo256, BlockNumberProvider};
+
+type Error = crate::Error<Test>;
+
+const RANDOMNESS: [u8; 32] = [7u8; 32];
+
+fn randomness_proof(randomness: [u8; 32]) -> (H256, StorageProof) {
+	relay_state_proof(&[(relay_babe_randomness_key(), randomness.encode())])
+}
+
+/// Inherent data of a parachain inherent whose relay chain state proof contains `entries`.
+fn parachain_inherent_data(entries: &[(Vec<u8>, Vec<u8>)]) -> InherentData {
+	let (root, proof) = relay_state_proof(entries);
+	let parachain_inherent = ParachainInherentData {
+		validation_data: PersistedValidationData {
+			relay_parent_number: 10,
+			relay_parent_storage_root: root,
+			..Default::default()
+		},
+		relay_chain_state: proof,
+		downward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+	};
+
+	let mut data = InherentData::new();
+	data.put_data(
+		cumulus_primitives_parachain_inherent::INHERENT_IDENTIFIER,
+		&parachain_inherent,
+	)
+	.unwrap();
+	data
+}
+
+#[test]
+fn on_validation_data_should_store_relay_parent_number_and_storage_root() {
+	new_test_ext().execute_with(|| {
+		set_validation_data(10, H256::repeat_byte(1));
+
+		assert_eq!(RelayChainInfo::relay_parent_number(), 10);
+		assert_eq!(RelayChainInfo::relay_parent_storage_root(), H256::repeat_byte(1));
+
+		set_validation_data(11, H256::repeat_byte(2));
+
+		assert_eq!(RelayChainInfo::relay_parent_number(), 11);
+		assert_eq!(RelayChainInfo::relay_parent_storage_root(), H256::repeat_byte(2));
+	});
+}
+
+#[test]
+fn current_block_number_should_be_relay_parent_number() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(RelayChainInfo::current_block_number(), 0);
+
+		set_validation_data(10, H256::repeat_byte(1));
+
+		assert_eq!(RelayChainInfo::current_block_number(), 10);
+	});
+}
+
+#[test]
+fn current_block_number_should_be_previous_relay_parent_number_during_on_initialize() {
+	new_test_ext().execute_with(|| {
+		set_validation_data(10, H256::repeat_byte(1));
+		RelayChainInfo::on_finalize(1);
+
+		System::set_block_number(2);
+		RelayChainInfo::on_initialize(2);
+
+		// validation data of block 2 is not set yet
+		assert_eq!(RelayChainInfo::current_block_number(), 10);
+
+		set_validation_data(12, H256::repeat_byte(2));
+
+		assert_eq!(RelayChainInfo::current_block_number(), 12);
+	});
+}
+
+#[test]
+fn set_relay_randomness_should_store_randomness_of_valid_proof() {
+	new_test_ext().execute_with(|| {
+		let (root, proof) = randomness_proof(RANDOMNESS);
+		set_validation_data(10, root);
+
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof));
+
+		assert_eq!(RelayChainInfo::relay_babe_randomness(), Some((RANDOMNESS, 1)));
+		assert_eq!(
+			RelayChainInfo::random(b"subject"),
+			(BlakeTwo256::hash_of(&(&b"subject"[..], RANDOMNESS)), 1)
+		);
+		assert_ne!(
+			RelayChainInfo::random(b"subject"),
+			RelayChainInfo::random(b"other subject")
+		);
+	});
+}
+
+#[test]
+fn set_relay_randomness_should_keep_block_of_known_randomness() {
+	new_test_ext().execute_with(|| {
+		let (root, proof) = randomness_proof(RANDOMNESS);
+		set_validation_data(10, root);
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof.clone()));
+		RelayChainInfo::on_finalize(1);
+
+		System::set_block_number(2);
+		set_validation_data(11, root);
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof));
+
+		assert_eq!(RelayChainInfo::relay_babe_randomness(), Some((RANDOMNESS, 1)));
+		RelayChainInfo::on_finalize(2);
+
+		System::set_block_number(3);
+		let (root, proof) = randomness_proof([8u8; 32]);
+		set_validation_data(12, root);
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof));
+
+		assert_eq!(RelayChainInfo::relay_babe_randomness(), Some(([8u8; 32], 3)));
+	});
+}
+
+#[test]
+fn set_relay_randomness_should_fail_when_proof_does_not_match_relay_parent_storage_root() {
+	new_test_ext().execute_with(|| {
+		let (_, proof) = randomness_proof(RANDOMNESS);
+		set_validation_data(10, H256::repeat_byte(1));
+
+		assert_noop!(
+			RelayChainInfo::set_relay_randomness(Origin::none(), proof),
+			Error::InvalidRelayChainStateProof
+		);
+	});
+}
+
+#[test]
+fn set_relay_randomness_should_fail_when_called_twice_in_block() {
+	new_test_ext().execute_with(|| {
+		let (root, proof) = randomness_proof(RANDOMNESS);
+		set_validation_data(10, root);
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof.clone()));
+
+		assert_noop!(
+			RelayChainInfo::set_relay_randomness(Origin::none(), proof.clone()),
+			Error::RandomnessAlreadyUpdated
+		);
+
+		RelayChainInfo::on_finalize(1);
+		System::set_block_number(2);
+
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof));
+	});
+}
+
+#[test]
+fn set_relay_randomness_should_fail_for_signed_origin() {
+	new_test_ext().execute_with(|| {
+		let (root, proof) = randomness_proof(RANDOMNESS);
+		set_validation_data(10, root);
+
+		assert_noop!(
+			RelayChainInfo::set_relay_randomness(Origin::signed(1), proof),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn random_should_signal_unknown_randomness() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(RelayChainInfo::try_random(b"subject"), None);
+		assert_eq!(RelayChainInfo::random(b"subject"), (H256::default(), u64::max_value()));
+
+		// proof without the randomness key keeps randomness unknown
+		let (root, proof) = relay_state_proof(&[(b"other key".to_vec(), vec![1])]);
+		set_validation_data(10, root);
+		assert_noop!(
+			RelayChainInfo::set_relay_randomness(Origin::none(), proof),
+			Error::MissingRelayRandomness
+		);
+
+		assert_eq!(RelayChainInfo::try_random(b"subject"), None);
+		assert_eq!(RelayChainInfo::random(b"subject"), (H256::default(), u64::max_value()));
+	});
+}
+
+#[test]
+fn create_inherent_should_take_randomness_key_from_relay_chain_state_proof() {
+	new_test_ext().execute_with(|| {
+		let mut entries: Vec<(Vec<u8>, Vec<u8>)> = (0u32..100).map(|i| (i.encode(), vec![1u8; 100])).collect();
+		entries.push((relay_babe_randomness_key(), RANDOMNESS.encode()));
+		let data = parachain_inherent_data(&entries);
+		let parachain_inherent: ParachainInherentData = data
+			.get_data(&cumulus_primitives_parachain_inherent::INHERENT_IDENTIFIER)
+			.unwrap()
+			.unwrap();
+
+		let call = RelayChainInfo::create_inherent(&data).expect("randomness key is in the proof");
+		assert!(RelayChainInfo::is_inherent(&call));
+		let proof = match call {
+			crate::Call::set_relay_randomness(proof) => proof,
+			_ => unreachable!(),
+		};
+
+		// the block does not carry the relay chain state proof twice
+		assert!(proof.encode().len() < parachain_inherent.relay_chain_state.encode().len() / 4);
+
+		set_validation_data(10, parachain_inherent.validation_data.relay_parent_storage_root);
+		assert_ok!(RelayChainInfo::set_relay_randomness(Origin::none(), proof));
+		assert_eq!(RelayChainInfo::relay_babe_randomness(), Some((RANDOMNESS, 1)));
+	});
+}
+
+#[test]
+fn create_inherent_should_not_create_call_without_randomness_key() {
+	new_test_ext().execute_with(|| {
+		let data = parachain_inherent_data(&[(b"other key".to_vec(), vec![1])]);
+
+		assert!(RelayChainInfo::create_inherent(&data).is_none());
+		assert!(RelayChainInfo::create_inherent(&InherentData::new()).is_none());
+	});
+}
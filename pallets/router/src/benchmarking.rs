@@ -0,0 +1,102 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrencyExtended;
+use primitives::{Amount, Price};
+use sp_runtime::FixedPointNumber;
+
+const SEED: u32 = 1;
+
+/// Assets of the routes are `FIRST_ASSET`, `FIRST_ASSET + 1`, ...
+const FIRST_ASSET: AssetId = 1_000;
+
+const LIQUIDITY: Balance = 1_000_000_000_000_000;
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn fund<T: pallet_xyk::Config>(who: &T::AccountId, asset_id: AssetId, amount: Balance) {
+	<T as pallet_xyk::Config>::Currency::update_balance(asset_id, who, amount as Amount).expect("account is funded");
+}
+
+/// Creates a route of `n` pools and returns it with a trader holding its first asset.
+fn setup<T: Config + pallet_xyk::Config>(n: u32) -> (Vec<AssetId>, T::AccountId) {
+	let owner: T::AccountId = account("owner", 0, SEED);
+	let route: Vec<AssetId> = (FIRST_ASSET..=FIRST_ASSET + n).collect();
+	for pair in route.windows(2) {
+		fund::<T>(&owner, pair[0], LIQUIDITY);
+		fund::<T>(&owner, pair[1], LIQUIDITY);
+		pallet_xyk::Pallet::<T>::create_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			pair[0],
+			pair[1],
+			LIQUIDITY,
+			Price::saturating_from_integer(1),
+		)
+		.expect("pool is created");
+	}
+
+	let trader: T::AccountId = account("trader", 0, SEED);
+	fund::<T>(&trader, route[0], 10 * AMOUNT);
+
+	(route, trader)
+}
+
+benchmarks! {
+	where_clause { where T: pallet_xyk::Config }
+
+	sell {
+		let n in 1 .. T::MaxRouteLength::get();
+		let (route, trader) = setup::<T>(n);
+		let asset_out = route[route.len() - 1];
+		let call = Call::<T>::sell(route, AMOUNT, 0);
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(trader.clone()).into())? }
+	verify {
+		assert_eq!(<T as Config>::Currency::free_balance(FIRST_ASSET, &trader), 9 * AMOUNT);
+		assert!(<T as Config>::Currency::free_balance(asset_out, &trader) > 0);
+	}
+
+	buy {
+		let n in 1 .. T::MaxRouteLength::get();
+		let (route, trader) = setup::<T>(n);
+		let asset_out = route[route.len() - 1];
+		let call = Call::<T>::buy(route, AMOUNT, 10 * AMOUNT);
+	}: { call.dispatch_bypass_filter(RawOrigin::Signed(trader.clone()).into())? }
+	verify {
+		assert_eq!(<T as Config>::Currency::free_balance(asset_out, &trader), AMOUNT);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_sell::<Test>());
+			assert_ok!(test_benchmark_buy::<Test>());
+		});
+	}
+}
@@ -0,0 +1,348 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Router pallet
+//!
+//! Trades along a route of XYK pools in a single transaction.
+//!
+//! A route is a list of distinct assets starting with the asset sold and ending with the asset
+//! bought. Each pair of consecutive assets is traded in its pool. The whole route is executed
+//! atomically with a single limit, the minimum amount received by `sell` or the maximum amount paid
+//! by `buy`.
+//!
+//! Amounts along a route are calculated with the price math and the exchange fee of the XYK pools.
+//! `best_sell_route` and `best_buy_route` search the direct route and the routes through
+//! `ConnectorAssets` for the best one.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::Get,
+	transactional,
+};
+use orml_traits::MultiCurrency;
+use primitives::{fee, traits::AMM, AssetId, AssetPair, Balance};
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// AMM to trade on.
+		type AMMPool: AMM<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Multi currency holding the pool reserves.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Exchange fee of the pools.
+		type ExchangeFee: Get<fee::Fee>;
+
+		/// Assets through which routes between two other assets are searched.
+		type ConnectorAssets: Get<Vec<AssetId>>;
+
+		/// Maximum number of pools in a route.
+		type MaxRouteLength: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Route has less than two assets, more than `MaxRouteLength` pools or repeats an asset.
+		InvalidRoute,
+		/// Pool of a pair of the route does not exist.
+		PoolNotFound,
+		/// Amounts along the route could not be calculated.
+		InvalidTradeAmount,
+		/// Amount received is lower than the minimum or amount paid is higher than the maximum.
+		TradingLimitReached,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Route was sold. \[who, route, amount sold, amount received\]
+		RouteSold(T::AccountId, Vec<AssetId>, Balance, Balance),
+
+		/// Route was bought. \[who, route, amount paid, amount bought\]
+		RouteBought(T::AccountId, Vec<AssetId>, Balance, Balance),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sell `amount` of the first asset of `route` for at least `min_received` of the last asset.
+		///
+		/// Emits `RouteSold` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::sell(route.len().saturating_sub(1) as u32))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			route: Vec<AssetId>,
+			amount: Balance,
+			min_received: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::validate_route(&route)?;
+
+			let received = Self::execute_sell(&who, &route, amount, min_received)?;
+
+			Self::deposit_event(Event::RouteSold(who, route, amount, received));
+
+			Ok(().into())
+		}
+
+		/// Buy `amount` of the last asset of `route` for at most `max_paid` of the first asset.
+		///
+		/// Emits `RouteBought` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::buy(route.len().saturating_sub(1) as u32))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			route: Vec<AssetId>,
+			amount: Balance,
+			max_paid: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::validate_route(&route)?;
+
+			let paid = Self::execute_buy(&who, &route, amount, max_paid)?;
+
+			Self::deposit_event(Event::RouteBought(who, route, paid, amount));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Route from `asset_in` to `asset_out` which receives the most for selling `amount` of
+	/// `asset_in`, with the amount received.
+	///
+	/// Shorter routes are preferred when amounts are equal.
+	pub fn best_sell_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)> {
+		Self::routes(asset_in, asset_out)
+			.into_iter()
+			.filter_map(|route| Self::calculate_sell(&route, amount).map(|received| (route, received)))
+			.max_by(|(route_a, a), (route_b, b)| a.cmp(b).then_with(|| route_b.len().cmp(&route_a.len())))
+	}
+
+	/// Route from `asset_in` to `asset_out` which pays the least for buying `amount` of `asset_out`,
+	/// with the amount paid.
+	///
+	/// Shorter routes are preferred when amounts are equal.
+	pub fn best_buy_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)> {
+		// Routes are ordered from the shortest, `min_by_key` keeps the first of equal elements.
+		Self::routes(asset_in, asset_out)
+			.into_iter()
+			.filter_map(|route| Self::calculate_buy(&route, amount).map(|paid| (route, paid)))
+			.min_by_key(|(_, paid)| *paid)
+	}
+
+	/// Amount of the last asset of `route` received for selling `amount` of the first asset.
+	pub fn calculate_sell(route: &[AssetId], amount: Balance) -> Option<Balance> {
+		route
+			.windows(2)
+			.try_fold(amount, |amount_in, pair| Self::sell_price(pair[0], pair[1], amount_in))
+	}
+
+	/// Amount of the first asset of `route` paid for buying `amount` of the last asset.
+	pub fn calculate_buy(route: &[AssetId], amount: Balance) -> Option<Balance> {
+		Self::buy_amounts(route, amount).and_then(|amounts| amounts.first().copied())
+	}
+
+	/// Routes from `asset_in` to `asset_out` of at most `MaxRouteLength` pools through distinct
+	/// connector assets, shortest first.
+	fn routes(asset_in: AssetId, asset_out: AssetId) -> Vec<Vec<AssetId>> {
+		if asset_in == asset_out {
+			return vec![];
+		}
+
+		let connectors: Vec<AssetId> = T::ConnectorAssets::get()
+			.into_iter()
+			.filter(|asset| *asset != asset_in && *asset != asset_out)
+			.collect();
+
+		let mut routes = vec![];
+		let mut paths = vec![vec![asset_in]];
+		for _ in 0..T::MaxRouteLength::get() {
+			let mut next_paths = vec![];
+			for path in paths {
+				let mut route = path.clone();
+				route.push(asset_out);
+				routes.push(route);
+
+				for connector in connectors.iter().filter(|connector| !path.contains(*connector)) {
+					let mut next_path = path.clone();
+					next_path.push(*connector);
+					next_paths.push(next_path);
+				}
+			}
+			paths = next_paths;
+		}
+
+		routes
+	}
+
+	/// Amounts of the assets of `route` traded when buying `amount` of the last asset.
+	///
+	/// The first amount is paid to the first pool, each following amount is bought from the
+	/// previous pool and paid to the next one.
+	fn buy_amounts(route: &[AssetId], amount: Balance) -> Option<Vec<Balance>> {
+		let mut amounts = vec![amount];
+		for pair in route.windows(2).rev() {
+			let amount_out = *amounts.last()?;
+			amounts.push(Self::buy_price(pair[0], pair[1], amount_out)?);
+		}
+		amounts.reverse();
+
+		Some(amounts)
+	}
+
+	/// Amount of `asset_out` received for selling `amount` of `asset_in` in their pool.
+	fn sell_price(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<Balance> {
+		let (reserve_in, reserve_out) = Self::reserves(asset_in, asset_out)?;
+
+		let amount_out = hydra_dx_math::calculate_sell_price(reserve_in, reserve_out, amount).ok()?;
+		if amount_out >= reserve_out {
+			return None;
+		}
+
+		amount_out.checked_sub(Self::exchange_fee(amount_out)?)
+	}
+
+	/// Amount of `asset_in` paid for buying `amount` of `asset_out` in their pool.
+	fn buy_price(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<Balance> {
+		let (reserve_in, reserve_out) = Self::reserves(asset_in, asset_out)?;
+		if amount >= reserve_out {
+			return None;
+		}
+
+		let amount_in = hydra_dx_math::calculate_buy_price(reserve_in, reserve_out, amount).ok()?;
+
+		amount_in.checked_add(Self::exchange_fee(amount_in)?)
+	}
+
+	/// Reserves of `asset_in` and `asset_out` in their pool.
+	fn reserves(asset_in: AssetId, asset_out: AssetId) -> Option<(Balance, Balance)> {
+		let pair = AssetPair { asset_in, asset_out };
+		if !T::AMMPool::exists(pair) {
+			return None;
+		}
+
+		let pool = T::AMMPool::get_pair_id(&pair);
+		Some((
+			T::Currency::free_balance(asset_in, &pool),
+			T::Currency::free_balance(asset_out, &pool),
+		))
+	}
+
+	/// Exchange fee of the pools charged on `amount`.
+	fn exchange_fee(amount: Balance) -> Option<Balance> {
+		let fee = T::ExchangeFee::get();
+		hydra_dx_math::fee::calculate_pool_trade_fee(amount, (fee.numerator, fee.denominator))
+	}
+
+	/// Ensure `route` is valid and all its pools exist.
+	fn validate_route(route: &[AssetId]) -> DispatchResult {
+		ensure!(route.len() >= 2, Error::<T>::InvalidRoute);
+		ensure!(route.len() - 1 <= T::MaxRouteLength::get() as usize, Error::<T>::InvalidRoute);
+		ensure!(
+			route.iter().enumerate().all(|(i, asset)| !route[..i].contains(asset)),
+			Error::<T>::InvalidRoute
+		);
+
+		let pool_exists = |pair: &[AssetId]| {
+			let (asset_in, asset_out) = (pair[0], pair[1]);
+			T::AMMPool::exists(AssetPair { asset_in, asset_out })
+		};
+		ensure!(route.windows(2).all(pool_exists), Error::<T>::PoolNotFound);
+
+		Ok(())
+	}
+
+	/// Sell along `route` and return the amount of the last asset received.
+	fn execute_sell(
+		who: &T::AccountId,
+		route: &[AssetId],
+		amount: Balance,
+		min_received: Balance,
+	) -> Result<Balance, DispatchError> {
+		let mut amount_in = amount;
+		for pair in route.windows(2) {
+			let (asset_in, asset_out) = (pair[0], pair[1]);
+			let before = T::Currency::free_balance(asset_out, who);
+			T::AMMPool::sell(who, AssetPair { asset_in, asset_out }, amount_in, Zero::zero(), false)?;
+			amount_in = T::Currency::free_balance(asset_out, who).saturating_sub(before);
+		}
+
+		ensure!(amount_in >= min_received, Error::<T>::TradingLimitReached);
+
+		Ok(amount_in)
+	}
+
+	/// Buy along `route` and return the amount of the first asset paid.
+	fn execute_buy(
+		who: &T::AccountId,
+		route: &[AssetId],
+		amount: Balance,
+		max_paid: Balance,
+	) -> Result<Balance, DispatchError> {
+		let amounts = Self::buy_amounts(route, amount).ok_or(Error::<T>::InvalidTradeAmount)?;
+		ensure!(amounts[0] <= max_paid, Error::<T>::TradingLimitReached);
+
+		for (i, pair) in route.windows(2).enumerate() {
+			let (asset_in, asset_out) = (pair[0], pair[1]);
+			T::AMMPool::buy(who, AssetPair { asset_in, asset_out }, amounts[i + 1], amounts[i], false)?;
+		}
+
+		Ok(amounts[0])
+	}
+}
@@ -0,0 +1,205 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_router;
+use crate::Config;
+use frame_support::parameter_types;
+use orml_traits::parameter_type_with_key;
+use primitives::{fee, Amount, AssetId, Balance, Price};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, FixedPointNumber,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = AccountId32;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const POOL_OWNER: AccountId = AccountId32::new([100u8; 32]);
+
+pub const BSX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const KSM: AssetId = 2;
+pub const ETH: AssetId = 3;
+pub const ACA: AssetId = 4;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>},
+		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
+		Router: pallet_router::{Pallet, Call, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxLocks: u32 = 50;
+	pub const NativeAssetId: AssetId = BSX;
+	pub ExchangeFee: fee::Fee = fee::Fee::default();
+	pub ConnectorAssets: Vec<AssetId> = vec![BSX];
+	pub const MaxRouteLength: u32 = 3;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+}
+
+impl pallet_asset_registry::Config for Test {
+	type AssetId = AssetId;
+}
+
+impl pallet_xyk::Config for Test {
+	type Event = Event;
+	type AssetPairAccountId = pallet_xyk::AssetPairAccountId<Self>;
+	type Currency = Tokens;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFee;
+}
+
+impl Config for Test {
+	type Event = Event;
+	type AMMPool = XYK;
+	type Currency = Tokens;
+	type ExchangeFee = ExchangeFee;
+	type ConnectorAssets = ConnectorAssets;
+	type MaxRouteLength = MaxRouteLength;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, DOT, 1_000 * ONE),
+				(POOL_OWNER, BSX, 100_000 * ONE),
+				(POOL_OWNER, DOT, 10_000 * ONE),
+				(POOL_OWNER, KSM, 10_000 * ONE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Test> {
+			core_asset_id: BSX,
+			asset_ids: vec![
+				(b"DOT".to_vec(), DOT),
+				(b"KSM".to_vec(), KSM),
+				(b"ETH".to_vec(), ETH),
+				(b"ACA".to_vec(), ACA),
+			],
+			next_asset_id: 5,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Creates the pools of the tests:
+/// - DOT/BSX with 1 000 DOT at the price of 10 BSX,
+/// - KSM/BSX with 1 000 KSM at the price of 10 BSX,
+/// - DOT/KSM with 100 DOT at the price of 1 KSM, a direct pool shallower than the route through BSX.
+pub fn create_pools() {
+	for (asset_a, asset_b, amount, price) in vec![
+		(DOT, BSX, 1_000 * ONE, 10),
+		(KSM, BSX, 1_000 * ONE, 10),
+		(DOT, KSM, 100 * ONE, 1),
+	] {
+		XYK::create_pool(
+			Origin::signed(POOL_OWNER),
+			asset_a,
+			asset_b,
+			amount,
+			Price::saturating_from_integer(price),
+		)
+		.expect("pool is created");
+	}
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
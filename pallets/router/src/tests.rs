@@ -0,0 +1,264 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+fn balance(asset_id: AssetId) -> Balance {
+	Tokens::free_balance(asset_id, &ALICE)
+}
+
+#[test]
+fn calculate_sell_should_charge_exchange_fee_on_amount_received() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		// 10 000 BSX * 250 DOT / (1 000 DOT + 250 DOT) = 2 000 BSX, minus the 0.2% fee
+		assert_eq!(Router::calculate_sell(&[DOT, BSX], 250 * ONE), Some(1_996 * ONE));
+
+		assert_ok!(Router::sell(Origin::signed(ALICE), vec![DOT, BSX], 250 * ONE, 0));
+		assert_eq!(balance(DOT), 750 * ONE);
+		assert_eq!(balance(BSX), 1_996 * ONE);
+	});
+}
+
+#[test]
+fn calculate_buy_should_charge_exchange_fee_on_amount_paid() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let price = hydra_dx_math::calculate_buy_price(1_000 * ONE, 10_000 * ONE, 2_000 * ONE).unwrap();
+		let fee = price * 2 / 1_000;
+		assert_eq!(Router::calculate_buy(&[DOT, BSX], 2_000 * ONE), Some(price + fee));
+
+		assert_ok!(Router::buy(
+			Origin::signed(ALICE),
+			vec![DOT, BSX],
+			2_000 * ONE,
+			price + fee
+		));
+		assert_eq!(balance(DOT), 1_000 * ONE - price - fee);
+		assert_eq!(balance(BSX), 2_000 * ONE);
+	});
+}
+
+#[test]
+fn calculate_should_fail_when_amount_exceeds_pool_reserve() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		assert_eq!(Router::calculate_buy(&[DOT, BSX], 10_000 * ONE), None);
+		assert_eq!(Router::calculate_buy(&[DOT, KSM, BSX], 10_000 * ONE), None);
+		assert_eq!(Router::calculate_sell(&[DOT, ETH], ONE), None);
+	});
+}
+
+#[test]
+fn sell_should_trade_along_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let route = vec![DOT, BSX, KSM];
+		let bsx_received = Router::calculate_sell(&[DOT, BSX], 10 * ONE).unwrap();
+		let expected = Router::calculate_sell(&[BSX, KSM], bsx_received).unwrap();
+		assert_eq!(Router::calculate_sell(&route, 10 * ONE), Some(expected));
+
+		assert_ok!(Router::sell(Origin::signed(ALICE), route.clone(), 10 * ONE, expected));
+
+		assert_eq!(balance(DOT), 990 * ONE);
+		assert_eq!(balance(BSX), 0);
+		assert_eq!(balance(KSM), expected);
+
+		expect_events(vec![Event::pallet_router(crate::Event::RouteSold(
+			ALICE,
+			route,
+			10 * ONE,
+			expected,
+		))]);
+	});
+}
+
+#[test]
+fn buy_should_trade_along_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let route = vec![DOT, BSX, KSM];
+		let bsx_paid = Router::calculate_buy(&[BSX, KSM], 10 * ONE).unwrap();
+		let expected = Router::calculate_buy(&[DOT, BSX], bsx_paid).unwrap();
+		assert_eq!(Router::calculate_buy(&route, 10 * ONE), Some(expected));
+
+		assert_ok!(Router::buy(Origin::signed(ALICE), route.clone(), 10 * ONE, expected));
+
+		assert_eq!(balance(DOT), 1_000 * ONE - expected);
+		assert_eq!(balance(BSX), 0);
+		assert_eq!(balance(KSM), 10 * ONE);
+
+		expect_events(vec![Event::pallet_router(crate::Event::RouteBought(
+			ALICE,
+			route,
+			expected,
+			10 * ONE,
+		))]);
+	});
+}
+
+#[test]
+fn sell_should_revert_all_trades_when_received_less_than_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let route = vec![DOT, BSX, KSM];
+		let expected = Router::calculate_sell(&route, 10 * ONE).unwrap();
+
+		assert_noop!(
+			Router::sell(Origin::signed(ALICE), route, 10 * ONE, expected + 1),
+			Error::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_paying_more_than_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let route = vec![DOT, BSX, KSM];
+		let expected = Router::calculate_buy(&route, 10 * ONE).unwrap();
+
+		assert_noop!(
+			Router::buy(Origin::signed(ALICE), route, 10 * ONE, expected - 1),
+			Error::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn trades_should_fail_when_route_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		for route in vec![vec![], vec![DOT], vec![DOT, BSX, DOT], vec![DOT, BSX, KSM, ETH, ACA]] {
+			assert_noop!(
+				Router::sell(Origin::signed(ALICE), route.clone(), ONE, 0),
+				Error::InvalidRoute
+			);
+			assert_noop!(
+				Router::buy(Origin::signed(ALICE), route, ONE, 1_000 * ONE),
+				Error::InvalidRoute
+			);
+		}
+	});
+}
+
+#[test]
+fn trades_should_fail_when_pool_of_route_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		assert_noop!(
+			Router::sell(Origin::signed(ALICE), vec![DOT, BSX, ETH], ONE, 0),
+			Error::PoolNotFound
+		);
+		assert_noop!(
+			Router::buy(Origin::signed(ALICE), vec![ETH, DOT], ONE, 1_000 * ONE),
+			Error::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trades_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		assert_noop!(Router::sell(Origin::root(), vec![DOT, BSX], ONE, 0), BadOrigin);
+		assert_noop!(Router::buy(Origin::none(), vec![DOT, BSX], ONE, 1_000 * ONE), BadOrigin);
+	});
+}
+
+#[test]
+fn best_sell_route_should_prefer_deeper_pools_for_large_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let via_bsx = Router::calculate_sell(&[DOT, BSX, KSM], 10 * ONE).unwrap();
+		assert!(via_bsx > Router::calculate_sell(&[DOT, KSM], 10 * ONE).unwrap());
+		assert_eq!(
+			Router::best_sell_route(DOT, KSM, 10 * ONE),
+			Some((vec![DOT, BSX, KSM], via_bsx))
+		);
+
+		// fees of the additional pool outweigh the price impact of small amounts
+		let direct = Router::calculate_sell(&[DOT, KSM], ONE / 1_000).unwrap();
+		assert!(direct > Router::calculate_sell(&[DOT, BSX, KSM], ONE / 1_000).unwrap());
+		assert_eq!(
+			Router::best_sell_route(DOT, KSM, ONE / 1_000),
+			Some((vec![DOT, KSM], direct))
+		);
+	});
+}
+
+#[test]
+fn best_buy_route_should_prefer_deeper_pools_for_large_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let via_bsx = Router::calculate_buy(&[DOT, BSX, KSM], 10 * ONE).unwrap();
+		assert!(via_bsx < Router::calculate_buy(&[DOT, KSM], 10 * ONE).unwrap());
+		assert_eq!(
+			Router::best_buy_route(DOT, KSM, 10 * ONE),
+			Some((vec![DOT, BSX, KSM], via_bsx))
+		);
+
+		let direct = Router::calculate_buy(&[DOT, KSM], ONE / 1_000).unwrap();
+		assert!(direct < Router::calculate_buy(&[DOT, BSX, KSM], ONE / 1_000).unwrap());
+		assert_eq!(
+			Router::best_buy_route(DOT, KSM, ONE / 1_000),
+			Some((vec![DOT, KSM], direct))
+		);
+	});
+}
+
+#[test]
+fn best_route_should_only_use_connectors_between_other_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		let direct = Router::calculate_sell(&[DOT, BSX], 10 * ONE).unwrap();
+		assert_eq!(
+			Router::best_sell_route(DOT, BSX, 10 * ONE),
+			Some((vec![DOT, BSX], direct))
+		);
+	});
+}
+
+#[test]
+fn best_route_should_not_exist_without_pools() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pools();
+
+		assert_eq!(Router::best_sell_route(DOT, ETH, ONE), None);
+		assert_eq!(Router::best_buy_route(ETH, KSM, ONE), None);
+		assert_eq!(Router::best_sell_route(DOT, DOT, ONE), None);
+		assert_eq!(Router::best_buy_route(DOT, DOT, ONE), None);
+	});
+}
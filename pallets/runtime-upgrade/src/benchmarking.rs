@@ -0,0 +1,49 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+
+benchmarks! {
+	authorize_upgrade {
+		let origin = T::AuthorizeOrigin::successful_origin();
+		let code_hash = T::Hashing::hash(b"new runtime code");
+		let call = Call::<T>::authorize_upgrade(code_hash);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let enactable_at = frame_system::Pallet::<T>::block_number() + T::UpgradeDelay::get();
+		assert_eq!(Pallet::<T>::authorized_upgrade(), Some((code_hash, enactable_at)));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_authorize_upgrade::<Test>());
+		});
+	}
+}
@@ -0,0 +1,158 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Runtime upgrade pallet
+//!
+//! Runtime upgrades in two phases, giving collators and indexers notice of upcoming upgrades.
+//!
+//! 1. `AuthorizeOrigin` authorizes the hash of the new runtime code with `authorize_upgrade`. The
+//!    upgrade can be enacted `UpgradeDelay` blocks later.
+//! 2. After the delay, anyone can submit the code matching the authorized hash with
+//!    `enact_authorized_upgrade`. The code must be a runtime with the same `spec_name` and a higher
+//!    `spec_version` than the current runtime. It is then set through `frame_system`, i.e. by
+//!    `OnSetCode` of the runtime.
+//!
+//! Authorizing another code hash replaces the previous authorization and restarts the delay.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Decode;
+use sp_runtime::traits::Hash;
+use sp_std::prelude::*;
+use sp_version::RuntimeVersion;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use frame_system::RawOrigin;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin which may authorize upgrades.
+		type AuthorizeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks between the authorization and the earliest enactment of an upgrade.
+		type UpgradeDelay: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No upgrade is authorized.
+		NothingAuthorized,
+		/// Code does not match the authorized code hash.
+		Unauthorized,
+		/// Delay of the authorized upgrade has not passed yet.
+		TooEarly,
+		/// Runtime version of the code could not be determined.
+		InvalidCode,
+		/// `spec_name` of the code differs from the current runtime.
+		InvalidSpecName,
+		/// `spec_version` of the code is not higher than the current runtime.
+		SpecVersionNotIncreased,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::Hash = "Hash", T::BlockNumber = "BlockNumber")]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Upgrade was authorized. \[code hash, earliest enactment block\]
+		UpgradeAuthorized(T::Hash, T::BlockNumber),
+
+		/// Authorized upgrade was enacted. \[code hash, spec version\]
+		UpgradeEnacted(T::Hash, u32),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn authorized_upgrade)]
+	/// Authorized code hash and the block from which the upgrade can be enacted.
+	pub type AuthorizedUpgrade<T: Config> = StorageValue<_, (T::Hash, T::BlockNumber), OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Authorize an upgrade to the runtime code with given hash.
+		///
+		/// Emits `UpgradeAuthorized` event when successful.
+		#[pallet::weight((<T as Config>::WeightInfo::authorize_upgrade(), DispatchClass::Operational))]
+		pub fn authorize_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResultWithPostInfo {
+			T::AuthorizeOrigin::ensure_origin(origin)?;
+
+			let enactable_at = frame_system::Pallet::<T>::block_number().saturating_add(T::UpgradeDelay::get());
+			AuthorizedUpgrade::<T>::put((code_hash, enactable_at));
+
+			Self::deposit_event(Event::UpgradeAuthorized(code_hash, enactable_at));
+
+			Ok(().into())
+		}
+
+		/// Enact the authorized upgrade with its runtime code.
+		///
+		/// Can be called by anyone once the delay of the authorization has passed.
+		///
+		/// Emits `UpgradeEnacted` event when successful.
+		#[pallet::weight((T::BlockWeights::get().max_block, DispatchClass::Operational))]
+		pub fn enact_authorized_upgrade(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (code_hash, enactable_at) = Self::authorized_upgrade().ok_or(Error::<T>::NothingAuthorized)?;
+			ensure!(T::Hashing::hash(&code) == code_hash, Error::<T>::Unauthorized);
+			ensure!(frame_system::Pallet::<T>::block_number() >= enactable_at, Error::<T>::TooEarly);
+
+			let new_version = sp_io::misc::runtime_version(&code)
+				.and_then(|version| RuntimeVersion::decode(&mut &version[..]).ok())
+				.ok_or(Error::<T>::InvalidCode)?;
+			let current_version = T::Version::get();
+			ensure!(new_version.spec_name == current_version.spec_name, Error::<T>::InvalidSpecName);
+			ensure!(new_version.spec_version > current_version.spec_version, Error::<T>::SpecVersionNotIncreased);
+
+			frame_system::Pallet::<T>::set_code(RawOrigin::Root.into(), code)?;
+
+			AuthorizedUpgrade::<T>::kill();
+
+			Self::deposit_event(Event::UpgradeEnacted(code_hash, new_version.spec_version));
+
+			Ok(Pays::No.into())
+		}
+	}
+}
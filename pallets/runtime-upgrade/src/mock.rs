@@ -0,0 +1,147 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_runtime_upgrade;
+use crate::Config;
+use codec::Encode;
+use frame_support::parameter_types;
+use frame_system::EnsureRoot;
+use sp_core::{traits::ReadRuntimeVersionExt, H256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use sp_version::RuntimeVersion;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+
+pub const UPGRADE_DELAY: u64 = 10;
+pub const SPEC_VERSION: u32 = 3;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		RuntimeUpgrade: pallet_runtime_upgrade::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub Version: RuntimeVersion = runtime_version("basilisk", SPEC_VERSION);
+	pub const UpgradeDelay: u64 = UPGRADE_DELAY;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = Version;
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+impl Config for Test {
+	type Event = Event;
+	type AuthorizeOrigin = EnsureRoot<AccountId>;
+	type UpgradeDelay = UpgradeDelay;
+	type WeightInfo = ();
+}
+
+pub fn runtime_version(spec_name: &'static str, spec_version: u32) -> RuntimeVersion {
+	RuntimeVersion {
+		spec_name: spec_name.into(),
+		spec_version,
+		..Default::default()
+	}
+}
+
+/// Reports the same runtime version for any code.
+struct ReadRuntimeVersion(Vec<u8>);
+impl sp_core::traits::ReadRuntimeVersion for ReadRuntimeVersion {
+	fn read_runtime_version(
+		&self,
+		_wasm_code: &[u8],
+		_ext: &mut dyn sp_externalities::Externalities,
+	) -> Result<Vec<u8>, String> {
+		if self.0.is_empty() {
+			return Err("invalid code".into());
+		}
+		Ok(self.0.clone())
+	}
+}
+
+pub struct ExtBuilder {
+	code_version: Option<RuntimeVersion>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			code_version: Some(runtime_version("basilisk", SPEC_VERSION + 1)),
+		}
+	}
+}
+
+impl ExtBuilder {
+	/// Runtime version of any code enacted, `None` for code without a runtime version.
+	pub fn with_code_version(mut self, version: Option<RuntimeVersion>) -> Self {
+		self.code_version = version;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		let version = self.code_version.map(|v| v.encode()).unwrap_or_default();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.register_extension(ReadRuntimeVersionExt::new(ReadRuntimeVersion(version)));
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,215 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::{traits::BlakeTwo256, DispatchError::BadOrigin};
+
+type Error = crate::Error<Test>;
+
+const CODE: &[u8] = b"new runtime code";
+
+fn code_hash(code: &[u8]) -> H256 {
+	BlakeTwo256::hash(code)
+}
+
+#[test]
+fn authorize_upgrade_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+
+		assert_eq!(
+			RuntimeUpgrade::authorized_upgrade(),
+			Some((code_hash(CODE), 1 + UPGRADE_DELAY))
+		);
+
+		expect_events(vec![Event::pallet_runtime_upgrade(crate::Event::UpgradeAuthorized(
+			code_hash(CODE),
+			1 + UPGRADE_DELAY,
+		))]);
+	});
+}
+
+#[test]
+fn authorize_upgrade_should_fail_when_origin_is_not_authorize_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::authorize_upgrade(Origin::signed(ALICE), code_hash(CODE)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn authorize_upgrade_should_replace_authorization_and_restart_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		let other_code = b"other runtime code";
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(other_code)));
+
+		System::set_block_number(5);
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+
+		assert_eq!(
+			RuntimeUpgrade::authorized_upgrade(),
+			Some((code_hash(CODE), 5 + UPGRADE_DELAY))
+		);
+
+		System::set_block_number(1 + UPGRADE_DELAY);
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), other_code.to_vec()),
+			Error::Unauthorized
+		);
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+			Error::TooEarly
+		);
+
+		System::set_block_number(5 + UPGRADE_DELAY);
+		assert_ok!(RuntimeUpgrade::enact_authorized_upgrade(
+			Origin::signed(ALICE),
+			CODE.to_vec()
+		));
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_work_after_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+
+		System::set_block_number(1 + UPGRADE_DELAY);
+		assert_ok!(RuntimeUpgrade::enact_authorized_upgrade(
+			Origin::signed(ALICE),
+			CODE.to_vec()
+		));
+
+		assert_eq!(
+			sp_io::storage::get(sp_core::storage::well_known_keys::CODE),
+			Some(CODE.to_vec())
+		);
+		assert_eq!(RuntimeUpgrade::authorized_upgrade(), None);
+
+		expect_events(vec![Event::pallet_runtime_upgrade(crate::Event::UpgradeEnacted(
+			code_hash(CODE),
+			SPEC_VERSION + 1,
+		))]);
+
+		// the authorization is used up
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+			Error::NothingAuthorized
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_nothing_is_authorized() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+			Error::NothingAuthorized
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+		System::set_block_number(1 + UPGRADE_DELAY);
+
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::none(), CODE.to_vec()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_before_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+
+		System::set_block_number(UPGRADE_DELAY);
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+			Error::TooEarly
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_code_hash_does_not_match() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+		System::set_block_number(1 + UPGRADE_DELAY);
+
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), b"malicious code".to_vec()),
+			Error::Unauthorized
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_code_has_no_runtime_version() {
+	ExtBuilder::default().with_code_version(None).build().execute_with(|| {
+		assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+		System::set_block_number(1 + UPGRADE_DELAY);
+
+		assert_noop!(
+			RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+			Error::InvalidCode
+		);
+	});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_spec_name_differs() {
+	ExtBuilder::default()
+		.with_code_version(Some(runtime_version("other", SPEC_VERSION + 1)))
+		.build()
+		.execute_with(|| {
+			assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+			System::set_block_number(1 + UPGRADE_DELAY);
+
+			assert_noop!(
+				RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+				Error::InvalidSpecName
+			);
+		});
+}
+
+#[test]
+fn enact_authorized_upgrade_should_fail_when_spec_version_is_not_increased() {
+	for spec_version in vec![SPEC_VERSION - 1, SPEC_VERSION] {
+		ExtBuilder::default()
+			.with_code_version(Some(runtime_version("basilisk", spec_version)))
+			.build()
+			.execute_with(|| {
+				assert_ok!(RuntimeUpgrade::authorize_upgrade(Origin::root(), code_hash(CODE)));
+				System::set_block_number(1 + UPGRADE_DELAY);
+
+				assert_noop!(
+					RuntimeUpgrade::enact_authorized_upgrade(Origin::signed(ALICE), CODE.to_vec()),
+					Error::SpecVersionNotIncreased
+				);
+			});
+	}
+}
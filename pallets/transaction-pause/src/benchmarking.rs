@@ -0,0 +1,61 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::UnfilteredDispatchable;
+
+benchmarks! {
+	pause_transaction {
+		let origin = T::UpdateOrigin::successful_origin();
+		let pallet_name = b"Balances".to_vec();
+		let function_name = b"transfer".to_vec();
+		let call = Call::<T>::pause_transaction(pallet_name.clone(), function_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(PausedTransactions::<T>::contains_key((pallet_name, function_name)));
+	}
+
+	unpause_transaction {
+		let origin = T::UpdateOrigin::successful_origin();
+		let pallet_name = b"Balances".to_vec();
+		let function_name = b"transfer".to_vec();
+		PausedTransactions::<T>::insert((pallet_name.clone(), function_name.clone()), ());
+		let call = Call::<T>::unpause_transaction(pallet_name.clone(), function_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!PausedTransactions::<T>::contains_key((pallet_name, function_name)));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_pause_transaction::<Test>());
+			assert_ok!(test_benchmark_unpause_transaction::<Test>());
+		});
+	}
+}
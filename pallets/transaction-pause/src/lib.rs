@@ -0,0 +1,243 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Transaction pause pallet
+//!
+//! Lets a privileged origin pause individual calls, or all calls of a pallet, without a runtime
+//! upgrade.
+//!
+//! Calls are keyed by pallet name and call name as reported by `GetCallMetadata`. An empty call
+//! name pauses every call of the pallet. Pallets listed in `NonPausablePallets` can never be
+//! paused.
+//!
+//! `PausedTransactionFilter` is meant to be used as `frame_system::Config::BaseCallFilter`, and
+//! `CheckTransactionPause` rejects paused calls already at transaction pool validation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchInfo, GetCallMetadata},
+	traits::{Filter, Get},
+};
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin which may pause and unpause calls.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Names of pallets whose calls can never be paused.
+		type NonPausablePallets: Get<Vec<Vec<u8>>>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Calls of the pallet cannot be paused.
+		CannotPause,
+		/// Pallet name or call name is not valid UTF-8.
+		InvalidCharacter,
+		/// Call is not paused.
+		NotPaused,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event {
+		/// Call was paused. \[pallet name, call name\]
+		TransactionPaused(Vec<u8>, Vec<u8>),
+
+		/// Call was unpaused. \[pallet name, call name\]
+		TransactionUnpaused(Vec<u8>, Vec<u8>),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_transactions)]
+	/// Paused calls, keyed by (pallet name, call name). Empty call name pauses the whole pallet.
+	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause `function_name` of `pallet_name`, or the whole pallet if `function_name` is empty.
+		///
+		/// Emits `TransactionPaused` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::pause_transaction())]
+		pub fn pause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				sp_std::str::from_utf8(&pallet_name).is_ok() && sp_std::str::from_utf8(&function_name).is_ok(),
+				Error::<T>::InvalidCharacter
+			);
+			ensure!(Self::is_pausable(&pallet_name), Error::<T>::CannotPause);
+
+			PausedTransactions::<T>::insert((pallet_name.clone(), function_name.clone()), ());
+
+			Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
+
+			Ok(().into())
+		}
+
+		/// Unpause `function_name` of `pallet_name`.
+		///
+		/// Emits `TransactionUnpaused` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::unpause_transaction())]
+		pub fn unpause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let key = (pallet_name, function_name);
+
+			ensure!(PausedTransactions::<T>::contains_key(&key), Error::<T>::NotPaused);
+
+			PausedTransactions::<T>::remove(&key);
+
+			Self::deposit_event(Event::TransactionUnpaused(key.0, key.1));
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn is_pausable(pallet_name: &[u8]) -> bool {
+		!T::NonPausablePallets::get().iter().any(|name| name.as_slice() == pallet_name)
+	}
+
+	/// Whether the call given by its metadata is paused.
+	pub fn is_paused(pallet_name: &str, function_name: &str) -> bool {
+		let pallet_name = pallet_name.as_bytes();
+		if !Self::is_pausable(pallet_name) {
+			return false;
+		}
+
+		PausedTransactions::<T>::contains_key((pallet_name.to_vec(), Vec::new()))
+			|| PausedTransactions::<T>::contains_key((pallet_name.to_vec(), function_name.as_bytes().to_vec()))
+	}
+}
+
+/// Call filter which lets through calls that are not paused.
+pub struct PausedTransactionFilter<T>(PhantomData<T>);
+impl<T: Config> Filter<<T as frame_system::Config>::Call> for PausedTransactionFilter<T>
+where
+	<T as frame_system::Config>::Call: GetCallMetadata,
+{
+	fn filter(call: &<T as frame_system::Config>::Call) -> bool {
+		let metadata = call.get_call_metadata();
+		!Pallet::<T>::is_paused(metadata.pallet_name, metadata.function_name)
+	}
+}
+
+/// Signed extension rejecting paused calls at transaction pool validation.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct CheckTransactionPause<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckTransactionPause<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckTransactionPause<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> Debug for CheckTransactionPause<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckTransactionPause")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckTransactionPause<T>
+where
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "CheckTransactionPause";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if PausedTransactionFilter::<T>::filter(call) {
+			Ok(ValidTransaction::default())
+		} else {
+			Err(InvalidTransaction::Call.into())
+		}
+	}
+}
@@ -0,0 +1,191 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::mock::*;
+use crate::{CheckTransactionPause, PausedTransactionFilter};
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::Filter};
+use sp_runtime::{
+	traits::{Dispatchable, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchError::BadOrigin,
+};
+
+type Error = crate::Error<Test>;
+
+fn transfer_call() -> Call {
+	Call::Balances(pallet_balances::Call::transfer(BOB, 10))
+}
+
+fn transfer_keep_alive_call() -> Call {
+	Call::Balances(pallet_balances::Call::transfer_keep_alive(BOB, 10))
+}
+
+fn validate(call: &Call) -> Result<(), TransactionValidityError> {
+	CheckTransactionPause::<Test>::new()
+		.validate(&ALICE, call, &call.get_dispatch_info(), 0)
+		.map(|_| ())
+}
+
+#[test]
+fn pause_transaction_should_filter_paused_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(PausedTransactionFilter::<Test>::filter(&transfer_call()));
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(())
+		);
+		assert!(!PausedTransactionFilter::<Test>::filter(&transfer_call()));
+		assert!(PausedTransactionFilter::<Test>::filter(&transfer_keep_alive_call()));
+		assert_noop!(transfer_call().dispatch(Origin::signed(ALICE)), BadOrigin);
+
+		expect_events(vec![Event::pallet_transaction_pause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+		))]);
+	});
+}
+
+#[test]
+fn pause_transaction_with_empty_call_name_should_filter_all_calls_of_pallet() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			vec![]
+		));
+
+		assert!(!PausedTransactionFilter::<Test>::filter(&transfer_call()));
+		assert!(!PausedTransactionFilter::<Test>::filter(&transfer_keep_alive_call()));
+		assert!(PausedTransactionFilter::<Test>::filter(&Call::System(
+			frame_system::Call::remark(vec![])
+		)));
+	});
+}
+
+#[test]
+fn pause_transaction_should_fail_for_non_pausable_pallet() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"System".to_vec(), b"remark".to_vec()),
+			Error::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), b"TransactionPause".to_vec(), vec![]),
+			Error::CannotPause
+		);
+	});
+}
+
+#[test]
+fn pause_transaction_should_fail_for_invalid_characters() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::root(), vec![0xff], b"transfer".to_vec()),
+			Error::InvalidCharacter
+		);
+	});
+}
+
+#[test]
+fn pause_transaction_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(Origin::signed(ALICE), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn unpause_transaction_should_let_call_through() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_eq!(
+			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			None
+		);
+		assert!(PausedTransactionFilter::<Test>::filter(&transfer_call()));
+		assert_ok!(transfer_call().dispatch(Origin::signed(ALICE)));
+
+		expect_events(vec![Event::pallet_transaction_pause(
+			crate::Event::TransactionUnpaused(b"Balances".to_vec(), b"transfer".to_vec()),
+		)]);
+	});
+}
+
+#[test]
+fn unpause_transaction_should_fail_when_call_is_not_paused() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec()),
+			Error::NotPaused
+		);
+	});
+}
+
+#[test]
+fn unpause_transaction_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_noop!(
+			TransactionPause::unpause_transaction(Origin::signed(ALICE), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn check_transaction_pause_should_reject_paused_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(validate(&transfer_call()));
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_eq!(
+			validate(&transfer_call()),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+		assert_ok!(validate(&transfer_keep_alive_call()));
+	});
+}
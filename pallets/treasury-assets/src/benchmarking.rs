@@ -0,0 +1,55 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::UnfilteredDispatchable;
+
+const SEED: u32 = 1;
+
+benchmarks! {
+	where_clause { where T::CurrencyId: From<u32>, T::Balance: From<u32> }
+
+	spend {
+		let origin = T::SpendOrigin::successful_origin();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let currency_id: T::CurrencyId = 1u32.into();
+		let amount: T::Balance = 1_000_000u32.into();
+		T::MultiCurrency::deposit(currency_id, &T::TreasuryAccount::get(), amount)?;
+		let call = Call::<T>::spend(currency_id, beneficiary.clone(), amount);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(T::MultiCurrency::free_balance(currency_id, &beneficiary), amount);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_spend::<Test>());
+		});
+	}
+}
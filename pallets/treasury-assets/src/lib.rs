@@ -0,0 +1,122 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Treasury assets pallet
+//!
+//! Spending of non-native assets held by the treasury.
+//!
+//! `pallet_treasury` only spends the native currency. Dust of other assets and XCM fees paid in
+//! other assets are deposited to `TreasuryAccount` as well, and `SpendOrigin` can transfer them
+//! to a beneficiary with `spend`. The native currency is spent through treasury proposals only.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{ensure, traits::Get};
+use orml_traits::MultiCurrency;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::AtLeast32BitUnsigned;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Balance type.
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize;
+
+		/// Asset type.
+		type CurrencyId: Parameter + Member + Copy + MaybeSerializeDeserialize + Ord;
+
+		/// Currency of the assets held by the treasury.
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Self::Balance>;
+
+		/// Native currency id, which is spent by treasury proposals instead.
+		type NativeCurrencyId: Get<Self::CurrencyId>;
+
+		/// Account of the treasury.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Origin which may spend the assets of the treasury.
+		type SpendOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The native currency is spent by treasury proposals.
+		NativeCurrency,
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId", T::CurrencyId = "CurrencyId", T::Balance = "Balance")]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Treasury asset was spent. \[currency id, beneficiary, amount\]
+		Spent(T::CurrencyId, T::AccountId, T::Balance),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer `amount` of `currency_id` from the treasury to `beneficiary`.
+		///
+		/// Emits `Spent` event when successful.
+		#[pallet::weight(<T as Config>::WeightInfo::spend())]
+		pub fn spend(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::SpendOrigin::ensure_origin(origin)?;
+
+			ensure!(currency_id != T::NativeCurrencyId::get(), Error::<T>::NativeCurrency);
+
+			T::MultiCurrency::transfer(currency_id, &T::TreasuryAccount::get(), &beneficiary, amount)?;
+
+			Self::deposit_event(Event::Spent(currency_id, beneficiary, amount));
+
+			Ok(().into())
+		}
+	}
+}
@@ -0,0 +1,64 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+
+type Error = crate::Error<Test>;
+
+#[test]
+fn spend_should_transfer_asset_from_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TreasuryAssets::spend(Origin::root(), DOT, ALICE, 400));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 400);
+		assert_eq!(Tokens::free_balance(DOT, &TREASURY), 600);
+
+		expect_events(vec![Event::pallet_treasury_assets(crate::Event::Spent(
+			DOT, ALICE, 400,
+		))]);
+	});
+}
+
+#[test]
+fn spend_should_fail_for_native_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryAssets::spend(Origin::root(), BSX, ALICE, 400),
+			Error::NativeCurrency
+		);
+	});
+}
+
+#[test]
+fn spend_should_fail_when_treasury_balance_is_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TreasuryAssets::spend(Origin::root(), DOT, ALICE, 1_001),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn spend_should_fail_with_unprivileged_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(TreasuryAssets::spend(Origin::signed(ALICE), DOT, ALICE, 400), BadOrigin);
+	});
+}
@@ -0,0 +1,98 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrencyExtended;
+use primitives::{Amount, Price};
+use sp_runtime::FixedPointNumber;
+
+const SEED: u32 = 1;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+const LIQUIDITY: Balance = 1_000_000_000_000_000;
+const AMOUNT: Balance = 1_000_000_000_000;
+
+fn fund<T: pallet_xyk::Config>(who: &T::AccountId, asset_id: AssetId, amount: Balance) {
+	<T as pallet_xyk::Config>::Currency::update_balance(asset_id, who, amount as Amount).expect("account is funded");
+}
+
+/// Creates a pool of `ASSET_A` and `ASSET_B` and returns the origin and the account of a trader
+/// holding `ASSET_A`.
+fn setup<T: Config + pallet_xyk::Config>() -> (T::Origin, T::AccountId) {
+	let owner: T::AccountId = account("owner", 0, SEED);
+	fund::<T>(&owner, ASSET_A, LIQUIDITY);
+	fund::<T>(&owner, ASSET_B, LIQUIDITY);
+	pallet_xyk::Pallet::<T>::create_pool(
+		RawOrigin::Signed(owner).into(),
+		ASSET_A,
+		ASSET_B,
+		LIQUIDITY,
+		Price::saturating_from_integer(1),
+	)
+	.expect("pool is created");
+
+	let origin = T::RemoteOrigin::successful_origin();
+	let location = T::RemoteOrigin::ensure_origin(origin.clone()).expect("successful origin has a location");
+	let trader = T::LocationToAccountId::convert_ref(&location).expect("location has an account");
+	fund::<T>(&trader, ASSET_A, 10 * AMOUNT);
+
+	(origin, trader)
+}
+
+benchmarks! {
+	where_clause { where T: pallet_xyk::Config }
+
+	sell {
+		let (origin, trader) = setup::<T>();
+		let call = Call::<T>::sell(0, ASSET_A, ASSET_B, AMOUNT, 0);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(<T as Config>::Currency::free_balance(ASSET_A, &trader), 9 * AMOUNT);
+		assert!(<T as Config>::Currency::free_balance(ASSET_B, &trader) > 0);
+	}
+
+	buy {
+		let (origin, trader) = setup::<T>();
+		let call = Call::<T>::buy(0, ASSET_B, ASSET_A, AMOUNT, 2 * AMOUNT);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(<T as Config>::Currency::free_balance(ASSET_B, &trader), AMOUNT);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(test_benchmark_sell::<Test>());
+			assert_ok!(test_benchmark_buy::<Test>());
+		});
+	}
+}
@@ -0,0 +1,241 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # XCM trade pallet
+//!
+//! Lets other chains trade on XYK through XCM `Transact` and reports the result back.
+//!
+//! The calls must be dispatched with the XCM origin of the sender (`RemoteOrigin`). The trade is
+//! executed by the account `LocationToAccountId` derives from the origin, i.e. the sovereign
+//! account of a sibling parachain or an account derived for a sub-location of it. The sender
+//! then receives a `QueryResponse` with given `query_id`. The response holds the amount of the
+//! bought asset or no assets if the trade failed. If the response can not be sent, the call fails
+//! and the trade is reverted.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Encode;
+use frame_support::{
+	dispatch::DispatchError,
+	storage::{with_transaction, TransactionOutcome},
+	transactional,
+};
+use orml_traits::MultiCurrency;
+use primitives::{traits::AMM, AssetId, AssetPair, Balance};
+use sp_runtime::traits::Convert;
+use sp_std::prelude::*;
+use xcm::v0::{Junction, MultiAsset, MultiLocation, Response, SendXcm, Xcm};
+use xcm_executor::traits::Convert as LocationConvert;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Origin of XCM `Transact`, resolving to the location of the sender.
+		type RemoteOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
+
+		/// Account executing the trade of a location.
+		type LocationToAccountId: LocationConvert<MultiLocation, Self::AccountId>;
+
+		/// AMM to trade on.
+		type AMMPool: AMM<Self::AccountId, AssetId, AssetPair, Balance>;
+
+		/// Multi currency used to determine the bought amount.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Location of an asset reported in the response.
+		type CurrencyIdConvert: Convert<AssetId, Option<MultiLocation>>;
+
+		/// Sender of the responses.
+		type XcmSender: SendXcm;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Origin location has no account.
+		UnknownLocation,
+		/// Response could not be sent to the origin.
+		ResponseNotSent,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event {
+		/// Remote trade was executed. \[origin, query id, asset bought, amount bought\]
+		Traded(MultiLocation, u64, AssetId, Balance),
+
+		/// Remote trade failed. \[origin, query id, error\]
+		TradeFailed(MultiLocation, u64, DispatchError),
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sell `amount` of `asset_in` for at least `min_bought` of `asset_out`.
+		///
+		/// Responds to the origin with the bought amount of `asset_out` under `query_id`.
+		///
+		/// Emits `Traded` or `TradeFailed` event. Fails only if the response cannot be sent, the
+		/// trade is reverted then.
+		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			query_id: u64,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			min_bought: Balance,
+		) -> DispatchResultWithPostInfo {
+			let location = T::RemoteOrigin::ensure_origin(origin)?;
+			let who = T::LocationToAccountId::convert_ref(&location).map_err(|_| Error::<T>::UnknownLocation)?;
+
+			let result = Self::trade(&who, asset_out, || {
+				T::AMMPool::sell(&who, AssetPair { asset_in, asset_out }, amount, min_bought, false)
+			});
+
+			Self::report(location, query_id, asset_out, result)?;
+
+			Ok(().into())
+		}
+
+		/// Buy `amount` of `asset_out` for at most `max_sold` of `asset_in`.
+		///
+		/// Responds to the origin with the bought amount of `asset_out` under `query_id`.
+		///
+		/// Emits `Traded` or `TradeFailed` event. Fails only if the response cannot be sent, the
+		/// trade is reverted then.
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			query_id: u64,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			max_sold: Balance,
+		) -> DispatchResultWithPostInfo {
+			let location = T::RemoteOrigin::ensure_origin(origin)?;
+			let who = T::LocationToAccountId::convert_ref(&location).map_err(|_| Error::<T>::UnknownLocation)?;
+
+			let result = Self::trade(&who, asset_out, || {
+				T::AMMPool::buy(&who, AssetPair { asset_in, asset_out }, amount, max_sold, false)
+			});
+
+			Self::report(location, query_id, asset_out, result)?;
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Execute `trade` and return the amount of `asset_out` bought by `who`.
+	///
+	/// Changes made by a failed trade are reverted.
+	fn trade(
+		who: &T::AccountId,
+		asset_out: AssetId,
+		trade: impl FnOnce() -> Result<(), DispatchError>,
+	) -> Result<Balance, DispatchError> {
+		with_transaction(|| {
+			let before = T::Currency::free_balance(asset_out, who);
+			match trade() {
+				Ok(()) => {
+					let bought = T::Currency::free_balance(asset_out, who).saturating_sub(before);
+					TransactionOutcome::Commit(Ok(bought))
+				}
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+	}
+
+	/// Send `QueryResponse` with the result of a trade to the chain of `location`.
+	fn report(
+		location: MultiLocation,
+		query_id: u64,
+		asset_out: AssetId,
+		result: Result<Balance, DispatchError>,
+	) -> Result<(), DispatchError> {
+		let assets = match result {
+			Ok(amount) => {
+				Self::deposit_event(Event::Traded(location.clone(), query_id, asset_out, amount));
+				let asset = match T::CurrencyIdConvert::convert(asset_out) {
+					Some(id) => MultiAsset::ConcreteFungible { id, amount },
+					None => MultiAsset::AbstractFungible {
+						id: asset_out.encode(),
+						amount,
+					},
+				};
+				vec![asset]
+			}
+			Err(e) => {
+				Self::deposit_event(Event::TradeFailed(location.clone(), query_id, e));
+				vec![]
+			}
+		};
+
+		let message = Xcm::QueryResponse {
+			query_id,
+			response: Response::Assets(assets),
+		};
+		T::XcmSender::send_xcm(Self::chain_of(location), message).map_err(|_| Error::<T>::ResponseNotSent.into())
+	}
+
+	/// Location of the chain a (sub-)location belongs to.
+	fn chain_of(location: MultiLocation) -> MultiLocation {
+		match location {
+			MultiLocation::X2(Junction::Parent, Junction::Parachain(id))
+			| MultiLocation::X3(Junction::Parent, Junction::Parachain(id), _) => {
+				MultiLocation::X2(Junction::Parent, Junction::Parachain(id))
+			}
+			MultiLocation::X1(Junction::Parent) | MultiLocation::X2(Junction::Parent, _) => {
+				MultiLocation::X1(Junction::Parent)
+			}
+			location => location,
+		}
+	}
+}
@@ -0,0 +1,281 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_xcm_trade;
+use crate::Config;
+use frame_support::{parameter_types, traits::EnsureOrigin};
+use frame_system::EnsureSigned;
+use orml_traits::parameter_type_with_key;
+use primitives::{fee, Amount, AssetId, Balance, Price};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+	AccountId32, FixedPointNumber,
+};
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use xcm::v0::{Error as XcmError, Junction, MultiLocation, NetworkId, Result as XcmResult, SendXcm, Xcm};
+use xcm_executor::traits::Convert as LocationConvert;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = AccountId32;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
+pub const POOL_OWNER: AccountId = AccountId32::new([100u8; 32]);
+
+pub const BSX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const KSM: AssetId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+/// Parachain of the remote accounts.
+pub const SIBLING: u32 = 2000;
+/// Parachain responses can not be sent to.
+pub const UNREACHABLE: u32 = 3000;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>},
+		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
+		XcmTrade: pallet_xcm_trade::{Pallet, Call, Event},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxLocks: u32 = 50;
+	pub const NativeAssetId: AssetId = BSX;
+	pub ExchangeFee: fee::Fee = fee::Fee::default();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+}
+
+impl pallet_asset_registry::Config for Test {
+	type AssetId = AssetId;
+}
+
+impl pallet_xyk::Config for Test {
+	type Event = Event;
+	type AssetPairAccountId = pallet_xyk::AssetPairAccountId<Self>;
+	type Currency = Tokens;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFee;
+}
+
+/// Location of an account of a remote chain. `CHARLIE` is the relay chain, `BOB` an account of
+/// `UNREACHABLE`.
+pub fn remote_location(who: &AccountId) -> MultiLocation {
+	if *who == CHARLIE {
+		return MultiLocation::X1(Junction::Parent);
+	}
+
+	let para_id = if *who == BOB { UNREACHABLE } else { SIBLING };
+	MultiLocation::X3(
+		Junction::Parent,
+		Junction::Parachain(para_id),
+		Junction::AccountId32 {
+			network: NetworkId::Any,
+			id: who.clone().into(),
+		},
+	)
+}
+
+/// Signed origins stand in for XCM origins of `remote_location`.
+pub struct EnsureRemote;
+impl EnsureOrigin<Origin> for EnsureRemote {
+	type Success = MultiLocation;
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		EnsureSigned::<AccountId>::try_origin(o).map(|who| remote_location(&who))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::signed(ALICE)
+	}
+}
+
+/// Converts remote accounts of parachains back to the account, other locations have no account.
+pub struct RemoteAccount;
+impl LocationConvert<MultiLocation, AccountId> for RemoteAccount {
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<AccountId, ()> {
+		match location.borrow() {
+			MultiLocation::X3(Junction::Parent, Junction::Parachain(_), Junction::AccountId32 { id, .. }) => {
+				Ok((*id).into())
+			}
+			_ => Err(()),
+		}
+	}
+}
+
+/// DOT is identified by the relay chain location, other assets have no location.
+pub struct CurrencyIdConvert;
+impl Convert<AssetId, Option<MultiLocation>> for CurrencyIdConvert {
+	fn convert(id: AssetId) -> Option<MultiLocation> {
+		match id {
+			DOT => Some(MultiLocation::X1(Junction::Parent)),
+			_ => None,
+		}
+	}
+}
+
+thread_local! {
+	static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// Messages sent by the pallet.
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+/// Records sent messages, fails to send to `UNREACHABLE`.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	fn send_xcm(dest: MultiLocation, msg: Xcm<()>) -> XcmResult {
+		if dest == MultiLocation::X2(Junction::Parent, Junction::Parachain(UNREACHABLE)) {
+			return Err(XcmError::Unroutable);
+		}
+		SENT_XCM.with(|sent| sent.borrow_mut().push((dest, msg)));
+		Ok(())
+	}
+}
+
+impl Config for Test {
+	type Event = Event;
+	type RemoteOrigin = EnsureRemote;
+	type LocationToAccountId = RemoteAccount;
+	type AMMPool = XYK;
+	type Currency = Tokens;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type XcmSender = TestSendXcm;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, DOT, 100 * ONE),
+				(BOB, DOT, 100 * ONE),
+				(CHARLIE, DOT, 100 * ONE),
+				(POOL_OWNER, DOT, 10_000 * ONE),
+				(POOL_OWNER, KSM, 10_000 * ONE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Test> {
+			core_asset_id: BSX,
+			asset_ids: vec![(b"DOT".to_vec(), DOT), (b"KSM".to_vec(), KSM)],
+			next_asset_id: 3,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			endowed_accounts: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		SENT_XCM.with(|sent| sent.borrow_mut().clear());
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Creates the DOT/KSM pool with 1 000 DOT at the price of 1 KSM.
+pub fn create_pool() {
+	XYK::create_pool(
+		Origin::signed(POOL_OWNER),
+		DOT,
+		KSM,
+		1_000 * ONE,
+		Price::saturating_from_integer(1),
+	)
+	.expect("pool is created");
+}
+
+pub fn expect_events(e: Vec<Event>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
@@ -0,0 +1,166 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError::BadOrigin;
+use xcm::v0::NetworkId;
+
+type Error = crate::Error<Test>;
+
+fn sibling() -> MultiLocation {
+	MultiLocation::X2(Junction::Parent, Junction::Parachain(SIBLING))
+}
+
+fn response(query_id: u64, assets: Vec<MultiAsset>) -> Xcm<()> {
+	Xcm::QueryResponse {
+		query_id,
+		response: Response::Assets(assets),
+	}
+}
+
+#[test]
+fn sell_should_trade_and_report_bought_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+
+		assert_ok!(XcmTrade::sell(Origin::signed(ALICE), 7, DOT, KSM, 10 * ONE, ONE));
+
+		let bought = Tokens::free_balance(KSM, &ALICE);
+		assert!(bought > ONE);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 90 * ONE);
+
+		// KSM has no location
+		let asset = MultiAsset::AbstractFungible {
+			id: KSM.encode(),
+			amount: bought,
+		};
+		assert_eq!(sent_xcm(), vec![(sibling(), response(7, vec![asset]))]);
+
+		expect_events(vec![Event::pallet_xcm_trade(crate::Event::Traded(
+			remote_location(&ALICE),
+			7,
+			KSM,
+			bought,
+		))]);
+	});
+}
+
+#[test]
+fn buy_should_trade_and_report_bought_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+		assert_ok!(XcmTrade::sell(Origin::signed(ALICE), 1, DOT, KSM, 20 * ONE, ONE));
+		let ksm = Tokens::free_balance(KSM, &ALICE);
+
+		assert_ok!(XcmTrade::buy(Origin::signed(ALICE), 2, DOT, KSM, 5 * ONE, 10 * ONE));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 85 * ONE);
+		assert!(Tokens::free_balance(KSM, &ALICE) < ksm);
+
+		let asset = MultiAsset::ConcreteFungible {
+			id: MultiLocation::X1(Junction::Parent),
+			amount: 5 * ONE,
+		};
+		assert_eq!(sent_xcm().last(), Some(&(sibling(), response(2, vec![asset]))));
+
+		expect_events(vec![Event::pallet_xcm_trade(crate::Event::Traded(
+			remote_location(&ALICE),
+			2,
+			DOT,
+			5 * ONE,
+		))]);
+	});
+}
+
+#[test]
+fn failed_trade_should_be_reverted_and_reported_without_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+
+		// limit can not be met
+		assert_ok!(XcmTrade::sell(Origin::signed(ALICE), 3, DOT, KSM, 10 * ONE, 100 * ONE));
+
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 100 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 0);
+		assert_eq!(sent_xcm(), vec![(sibling(), response(3, vec![]))]);
+
+		assert!(matches!(
+			System::events().last().map(|record| record.event.clone()),
+			Some(Event::pallet_xcm_trade(crate::Event::TradeFailed(_, 3, _)))
+		));
+	});
+}
+
+#[test]
+fn trade_should_be_reverted_when_response_can_not_be_sent() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+
+		assert_noop!(
+			XcmTrade::sell(Origin::signed(BOB), 4, DOT, KSM, 10 * ONE, ONE),
+			Error::ResponseNotSent
+		);
+		assert_noop!(
+			XcmTrade::buy(Origin::signed(BOB), 5, KSM, DOT, ONE, 10 * ONE),
+			Error::ResponseNotSent
+		);
+
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 100 * ONE);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn trade_should_fail_when_origin_location_has_no_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+
+		assert_noop!(
+			XcmTrade::sell(Origin::signed(CHARLIE), 6, DOT, KSM, 10 * ONE, ONE),
+			Error::UnknownLocation
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_origin_is_not_remote() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool();
+
+		assert_noop!(XcmTrade::sell(Origin::root(), 6, DOT, KSM, 10 * ONE, ONE), BadOrigin);
+		assert_noop!(XcmTrade::buy(Origin::none(), 6, KSM, DOT, ONE, 10 * ONE), BadOrigin);
+	});
+}
+
+#[test]
+fn chain_of_should_strip_sub_locations() {
+	assert_eq!(XcmTrade::chain_of(remote_location(&ALICE)), sibling());
+	assert_eq!(XcmTrade::chain_of(sibling()), sibling());
+	assert_eq!(
+		XcmTrade::chain_of(MultiLocation::X2(
+			Junction::Parent,
+			Junction::AccountId32 {
+				network: NetworkId::Any,
+				id: [1u8; 32]
+			}
+		)),
+		MultiLocation::X1(Junction::Parent)
+	);
+}
@@ -0,0 +1,88 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC methods for on-chain identities.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub use identity_runtime_api::IdentityApi as IdentityRuntimeApi;
+
+#[rpc]
+pub trait IdentityApi<BlockHash, AccountId> {
+	/// Display name of `who`, if it has an identity set.
+	#[rpc(name = "identity_displayName")]
+	fn display_name(&self, who: AccountId, at: Option<BlockHash>) -> Result<Option<Bytes>>;
+}
+
+/// A struct that implements the [`IdentityApi`].
+pub struct Identity<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Identity<C, B> {
+	/// Create new `Identity` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId> IdentityApi<<Block as BlockT>::Hash, AccountId> for Identity<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: IdentityRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn display_name(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<Bytes>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.display_name(&at, who)
+			.map(|name| name.map(Into::into))
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query display name.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
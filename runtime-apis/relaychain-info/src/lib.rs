@@ -0,0 +1,38 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for relay chain info.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the relay parent of a parachain block.
+	pub trait RelayChainInfoApi<BlockNumber, Hash> where
+		BlockNumber: Codec,
+		Hash: Codec,
+	{
+		/// Block number of the relay parent.
+		fn relay_parent_number() -> BlockNumber;
+
+		/// Storage root of the relay parent.
+		fn relay_parent_storage_root() -> Hash;
+	}
+}
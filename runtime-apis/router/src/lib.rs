@@ -0,0 +1,41 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2021 Intergalactic Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for trade routes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to find routes of XYK pools between two assets.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Route from `asset_in` to `asset_out` which receives the most for selling `amount` of
+		/// `asset_in`, with the amount received.
+		fn best_sell_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)>;
+
+		/// Route from `asset_in` to `asset_out` which pays the least for buying `amount` of
+		/// `asset_out`, with the amount paid.
+		fn best_buy_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)>;
+	}
+}
@@ -0,0 +1,236 @@
+use crate::{
+	AccountId, Amount, AssetId, AuthorFeeShare, Authorship, Balance, Balances, BlockInflation, BlockNumber, Currencies,
+	ExistentialDeposit, ExtrinsicBaseWeight, NativeAssetId, Runtime, TargetTransferFee, TargetXYKTradeFee,
+	TransactionPayment, Treasury, XYK,
+};
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Currency, Get, Imbalance, OnUnbalanced};
+use frame_support::{ensure, PalletId};
+use frame_support::weights::{Weight, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial};
+use pallet_balances::WeightInfo as BalancesWeightInfo;
+use pallet_xyk::weights::WeightInfo as XYKWeightInfo;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use smallvec::smallvec;
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	DispatchError, FixedPointNumber, Perbill,
+};
+use sp_std::marker::PhantomData;
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Deposits the imbalance into the account of the current block author.
+///
+/// `Authorship::author()` falls back to the default account when the author is not known, the
+/// imbalance goes to the treasury in that case.
+pub struct ToAuthor;
+impl OnUnbalanced<NegativeImbalance> for ToAuthor {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		let author = Authorship::author();
+		if author == AccountId::default() {
+			Treasury::on_unbalanced(amount);
+		} else {
+			Balances::resolve_creating(&author, amount);
+		}
+	}
+}
+
+/// Splits transaction fees between the block author and the treasury.
+///
+/// `AuthorFeeShare` of the fee goes to the author, the rest to the treasury. Tips go to the author.
+/// Fees paid in non-native currencies are swapped to BSX by `MultiTransactionPayment` before they
+/// are withdrawn, so all fees end up here as native imbalance.
+pub struct DealWithFees;
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let author_amount: Balance = AuthorFeeShare::get() * fees.peek();
+			let (mut to_author, to_treasury) = fees.split(author_amount);
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut to_author);
+			}
+			ToAuthor::on_unbalanced(to_author);
+			Treasury::on_unbalanced(to_treasury);
+		}
+	}
+}
+
+/// Issues `BlockInflation` BSX to the author of each block.
+pub struct BlockReward;
+impl pallet_authorship::EventHandler<AccountId, BlockNumber> for BlockReward {
+	fn note_author(author: AccountId) {
+		let reward = BlockInflation::get();
+		if !reward.is_zero() {
+			// Dropping the positive imbalance increases the total issuance.
+			let _ = Balances::deposit_creating(&author, reward);
+		}
+	}
+
+	fn note_uncle(_author: AccountId, _age: BlockNumber) {}
+}
+
+/// `WeightToFee` scaled by the current fee multiplier of `pallet_transaction_payment`.
+///
+/// Used by `MultiTransactionPayment` so that fees it charges follow the block fullness in the
+/// same way as regular transaction fees.
+pub struct MultipliedWeightToFee<W>(PhantomData<W>);
+impl<W: WeightToFeePolynomial<Balance = Balance>> WeightToFeePolynomial for MultipliedWeightToFee<W> {
+	type Balance = Balance;
+
+	fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+		W::polynomial()
+	}
+
+	fn calc(weight: &Weight) -> Self::Balance {
+		TransactionPayment::next_fee_multiplier().saturating_mul_int(W::calc(weight))
+	}
+}
+
+/// Converts weight to BSX fee.
+///
+/// The fee is `a + b * weight`, fitted so that a balance transfer costs `TargetTransferFee` and an
+/// XYK sell costs `TargetXYKTradeFee` with a fee multiplier of one, not counting the length fee.
+/// Both the base extrinsic weight and the call weight are converted, so the constant part is
+/// paid twice for every extrinsic.
+///
+/// If the targets cannot be met with a non-negative constant part, the constant part is zero and
+/// the transfer pays more than its target.
+pub struct WeightToFee;
+impl WeightToFeePolynomial for WeightToFee {
+	type Balance = Balance;
+
+	fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+		let base_weight = Balance::from(ExtrinsicBaseWeight::get());
+		let transfer_weight = Balance::from(<Runtime as pallet_balances::Config>::WeightInfo::transfer());
+		let trade_weight = Balance::from(<Runtime as pallet_xyk::Config>::WeightInfo::sell());
+		let transfer_fee = TargetTransferFee::get();
+		let trade_fee = TargetXYKTradeFee::get();
+
+		// b = (trade_fee - transfer_fee) / (trade_weight - transfer_weight)
+		let (numerator, denominator) = if trade_weight > transfer_weight && trade_fee > transfer_fee {
+			(trade_fee - transfer_fee, trade_weight - transfer_weight)
+		} else {
+			(transfer_fee, base_weight.saturating_add(transfer_weight).max(1))
+		};
+		let coeff_integer = numerator / denominator;
+		let coeff_frac = Perbill::from_rational_approximation(numerator % denominator, denominator);
+
+		// 2a = transfer_fee - b * (base_weight + transfer_weight)
+		let transfer_weight_fee = coeff_integer
+			.saturating_mul(base_weight.saturating_add(transfer_weight))
+			.saturating_add(coeff_frac * base_weight.saturating_add(transfer_weight));
+		let constant = transfer_fee.saturating_sub(transfer_weight_fee) / 2;
+
+		smallvec![
+			WeightToFeeCoefficient {
+				degree: 0,
+				negative: false,
+				coeff_frac: Perbill::zero(),
+				coeff_integer: constant,
+			},
+			WeightToFeeCoefficient {
+				degree: 1,
+				negative: false,
+				coeff_frac,
+				coeff_integer,
+			},
+		]
+	}
+}
+
+/// Accounts protected from dusting: XYK pool accounts and pallet accounts.
+pub struct ProtectedAccounts;
+impl pallet_duster::DustRemovalWhitelist<AccountId> for ProtectedAccounts {
+	fn contains(who: &AccountId) -> bool {
+		PalletId::try_from_account(who).is_some() || XYK::get_pool_balances(who.clone()).is_some()
+	}
+}
+
+/// `Currencies` for XYK which never leaves native dust on a pool account.
+///
+/// `pallet_balances` reaps native balances below the existential deposit, which would silently
+/// remove BSX from the reserve of a pool. Withdrawals from a pool account which would leave less
+/// than the existential deposit of BSX fail instead. Withdrawing the whole reserve is allowed, as
+/// happens when the last liquidity is removed and the pool is destroyed.
+pub struct PoolCurrency;
+impl PoolCurrency {
+	/// Returned for withdrawals which would leave native dust on a pool account.
+	pub const NATIVE_DUST: DispatchError = DispatchError::Other("PoolNativeDust");
+
+	fn ensure_no_native_dust(currency_id: AssetId, who: &AccountId, amount: Balance) -> DispatchResult {
+		if currency_id != NativeAssetId::get() || XYK::get_pool_balances(who.clone()).is_none() {
+			return Ok(());
+		}
+
+		let remaining = Currencies::total_balance(currency_id, who).saturating_sub(amount);
+		ensure!(
+			remaining.is_zero() || remaining >= ExistentialDeposit::get(),
+			Self::NATIVE_DUST
+		);
+		Ok(())
+	}
+}
+
+impl MultiCurrency<AccountId> for PoolCurrency {
+	type CurrencyId = AssetId;
+	type Balance = Balance;
+
+	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
+		Currencies::minimum_balance(currency_id)
+	}
+
+	fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
+		Currencies::total_issuance(currency_id)
+	}
+
+	fn total_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+		Currencies::total_balance(currency_id, who)
+	}
+
+	fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+		Currencies::free_balance(currency_id, who)
+	}
+
+	fn ensure_can_withdraw(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_no_native_dust(currency_id, who, amount)?;
+		Currencies::ensure_can_withdraw(currency_id, who, amount)
+	}
+
+	fn transfer(
+		currency_id: Self::CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::ensure_no_native_dust(currency_id, from, amount)?;
+		Currencies::transfer(currency_id, from, to, amount)
+	}
+
+	fn deposit(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		Currencies::deposit(currency_id, who, amount)
+	}
+
+	fn withdraw(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_no_native_dust(currency_id, who, amount)?;
+		Currencies::withdraw(currency_id, who, amount)
+	}
+
+	fn can_slash(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> bool {
+		Currencies::can_slash(currency_id, who, value)
+	}
+
+	fn slash(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> Self::Balance {
+		Currencies::slash(currency_id, who, amount)
+	}
+}
+
+impl MultiCurrencyExtended<AccountId> for PoolCurrency {
+	type Amount = Amount;
+
+	fn update_balance(currency_id: Self::CurrencyId, who: &AccountId, by_amount: Self::Amount) -> DispatchResult {
+		if by_amount < 0 {
+			Self::ensure_no_native_dust(currency_id, who, by_amount.unsigned_abs())?;
+		}
+		Currencies::update_balance(currency_id, who, by_amount)
+	}
+}
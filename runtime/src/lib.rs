@@ -8,14 +8,17 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode};
 use sp_api::impl_runtime_apis;
-use sp_core::OpaqueMetadata;
-use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, IdentityLookup, Verify};
+use sp_core::{
+	u32_trait::{_1, _2, _3, _4},
+	OpaqueMetadata,
+};
+use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, IdentityLookup, OpaqueKeys, Verify};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::Zero,
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, RuntimeDebug,
 };
 use sp_std::convert::From;
 use sp_std::prelude::*;
@@ -26,23 +29,31 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{Get, KeyOwnerProofSystem, LockIdentifier, Randomness},
+	traits::{Get, InstanceFilter, KeyOwnerProofSystem, LockIdentifier, Randomness},
 	weights::{
 		constants::{BlockExecutionWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		DispatchClass, IdentityFee, Pays, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
+use frame_system::{EnsureOneOf, EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{FixedPointNumber, Perbill, Permill, Perquintill};
 
 use primitives::fee;
 
 mod currency;
+mod impls;
+mod migrations;
+#[cfg(test)]
+mod tests;
+mod xcm_config;
+
+use impls::{BlockReward, DealWithFees, MultipliedWeightToFee, PoolCurrency, ProtectedAccounts, WeightToFee};
 
 use pallet_xyk_rpc_runtime_api as xyk_rpc;
 
@@ -55,6 +66,7 @@ pub use pallet_asset_registry;
 pub use pallet_faucet;
 
 use pallet_transaction_multi_payment::{weights::WeightInfo, MultiCurrencyAdapter};
+use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -106,10 +118,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("basilisk"),
 	impl_name: create_runtime_str!("basilisk"),
 	authoring_version: 1,
-	spec_version: 3,
+	spec_version: 5,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 //TODO is 6s safe?
@@ -124,6 +136,15 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+// BSX has 12 decimals.
+pub const UNITS: Balance = 1_000_000_000_000;
+pub const CENTS: Balance = UNITS / 100;
+pub const MILLICENTS: Balance = CENTS / 1_000;
+
+pub const fn deposit(items: u32, bytes: u32) -> Balance {
+	items as Balance * 2 * UNITS + (bytes as Balance) * 30 * MILLICENTS
+}
+
 // 1 in 4 blocks (on average, not counting collisions) will be primary babe blocks.
 pub const PRIMARY_PROBABILITY: (u64, u64) = (1, 4);
 
@@ -179,7 +200,7 @@ parameter_types! {
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = ();
+	type BaseCallFilter = pallet_transaction_pause::PausedTransactionFilter<Runtime>;
 	type BlockWeights = BlockWeights;
 	type BlockLength = BlockLength;
 	/// The ubiquitous origin type.
@@ -240,7 +261,7 @@ impl pallet_timestamp::Config for Runtime {
 }
 
 parameter_types! {
-	pub const ExistentialDeposit: u128 = 0;
+	pub const ExistentialDeposit: Balance = CENTS;
 	pub const MaxLocks: u32 = 50;
 }
 
@@ -250,22 +271,47 @@ impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	/// The ubiquitous event type.
 	type Event = Event;
-	type DustRemoval = ();
+	type DustRemoval = Treasury;
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = ();
 }
 
 parameter_types! {
-	pub const TransactionByteFee: Balance = 1;
+	/// Share of transaction fees paid to the block author, the rest goes to the treasury.
+	pub const AuthorFeeShare: Permill = Permill::from_percent(20);
+	/// BSX issued to the block author on top of the fees. Zero disables the issuance.
+	pub const BlockInflation: Balance = 0;
+}
+
+parameter_types! {
+	pub const TransactionByteFee: Balance = MILLICENTS;
+	/// Fee of a balance transfer targeted by `WeightToFee`.
+	pub const TargetTransferFee: Balance = CENTS;
+	/// Fee of an XYK trade targeted by `WeightToFee`.
+	pub const TargetXYKTradeFee: Balance = 5 * CENTS;
 	pub const MultiPaymentCurrencySetFee: Pays = Pays::No;
+	/// The portion of the `NORMAL_DISPATCH_RATIO` that we adjust the fees with. Blocks filled less
+	/// than this will decrease the weight and more will increase.
+	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+	/// The adjustment variable of the runtime. Higher values will cause `TargetBlockFullness` to
+	/// change the fees more rapidly.
+	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(3, 100_000);
+	/// Minimum amount of the multiplier. This value cannot be too low. A test case should ensure
+	/// that combined with `AdjustmentVariable`, we can recover from the minimum.
+	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000u128);
 }
 
+/// Parameterized slow adjusting fee updated based on
+/// https://w3f-research.readthedocs.io/en/latest/polkadot/Token%20Economics.html#-2.-slow-adjusting-mechanism
+pub type SlowAdjustingFeeUpdate<R> =
+	TargetedFeeAdjustment<R, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
+
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = MultiCurrencyAdapter<Balances, (), MultiTransactionPayment>;
+	type OnChargeTransaction = MultiCurrencyAdapter<Balances, DealWithFees, MultiTransactionPayment>;
 	type TransactionByteFee = TransactionByteFee;
-	type WeightToFee = IdentityFee<Balance>;
-	type FeeMultiplierUpdate = ();
+	type WeightToFee = WeightToFee;
+	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 }
 
 impl pallet_transaction_multi_payment::Config for Runtime {
@@ -275,7 +321,7 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type AMMPool = XYK;
 	type WeightInfo = pallet_transaction_multi_payment::weights::HydraWeight<Runtime>;
 	type WithdrawFeeForSetCurrency = MultiPaymentCurrencySetFee;
-	type WeightToFee = IdentityFee<Balance>;
+	type WeightToFee = MultipliedWeightToFee<WeightToFee>;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -283,9 +329,212 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+/// Governance configuration
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
+	pub const CouncilMaxProposals: u32 = 30;
+	pub const CouncilMaxMembers: u32 = 13;
+}
+
+type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const TechnicalMotionDuration: BlockNumber = 5 * DAYS;
+	pub const TechnicalMaxProposals: u32 = 20;
+	pub const TechnicalMaxMembers: u32 = 10;
+}
+
+type TechnicalCollective = pallet_collective::Instance2;
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = TechnicalMotionDuration;
+	type MaxProposals = TechnicalMaxProposals;
+	type MaxMembers = TechnicalMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = ();
+}
+
+/// More than half of the council.
+pub type MoreThanHalfCouncil = pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>;
+/// At least two thirds of the council.
+pub type TwoThirdsCouncil = pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>;
+/// More than half of the technical committee.
+pub type MoreThanHalfTechnicalCommittee =
+	pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>;
+/// The whole technical committee.
+pub type AllTechnicalCommittee = pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>;
+
+/// At least three quarters of the council.
+pub type ThreeQuartersCouncil = pallet_collective::EnsureProportionAtLeast<_3, _4, AccountId, CouncilCollective>;
+/// The whole council.
+pub type AllCouncil = pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, CouncilCollective>;
+/// At least two thirds of the technical committee.
+pub type TwoThirdsTechnicalCommittee =
+	pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>;
+
+/// Root or more than half of the council.
+pub type EnsureRootOrMoreThanHalfCouncil = EnsureOneOf<AccountId, EnsureRoot<AccountId>, MoreThanHalfCouncil>;
+/// Root or at least two thirds of the council.
+pub type EnsureRootOrTwoThirdsCouncil = EnsureOneOf<AccountId, EnsureRoot<AccountId>, TwoThirdsCouncil>;
+/// Root or more than half of the technical committee.
+pub type EnsureRootOrMoreThanHalfTechnicalCommittee =
+	EnsureOneOf<AccountId, EnsureRoot<AccountId>, MoreThanHalfTechnicalCommittee>;
+
+parameter_types! {
+	/// Pallets which must keep working for the chain to produce blocks and to be governed.
+	pub NonPausablePallets: Vec<Vec<u8>> = vec![
+		b"System".to_vec(),
+		b"ParachainSystem".to_vec(),
+		b"RelayChainInfo".to_vec(),
+		b"RuntimeUpgrade".to_vec(),
+		b"Timestamp".to_vec(),
+		b"Sudo".to_vec(),
+		b"Council".to_vec(),
+		b"TechnicalCommittee".to_vec(),
+		b"TransactionPause".to_vec(),
+	];
+}
+
+impl pallet_transaction_pause::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type NonPausablePallets = NonPausablePallets;
+	type WeightInfo = pallet_transaction_pause::weights::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Scheduled calls may use up to 80% of the weight reserved for `on_initialize`, the rest is left
+	/// for other hooks. Calls with hard deadline priority (e.g. referendum enactments) are executed
+	/// even when they exceed it.
+	pub MaximumSchedulerWeight: Weight =
+		Perbill::from_percent(80) * (AVERAGE_ON_INITIALIZE_RATIO * BlockWeights::get().max_block);
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 100 * UNITS;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+/// Treasury of BSX, funded by its share of transaction fees and by BSX dust.
+///
+/// Transaction fees paid in other assets are swapped to BSX before they reach the treasury. Dust of
+/// other assets and XCM fees paid in other assets are deposited to `TreasuryAccount` as `orml_tokens`
+/// balances, which are spent by `TreasuryAssets` instead of treasury proposals.
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRootOrTwoThirdsCouncil;
+	type RejectOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type Event = Event;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = ();
+}
+
+impl pallet_treasury_assets::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type CurrencyId = AssetId;
+	type MultiCurrency = Currencies;
+	type NativeCurrencyId = NativeAssetId;
+	type TreasuryAccount = TreasuryAccount;
+	type SpendOrigin = EnsureRootOrTwoThirdsCouncil;
+	type WeightInfo = pallet_treasury_assets::weights::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+	pub const VotingPeriod: BlockNumber = 7 * DAYS;
+	pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+	pub const InstantAllowed: bool = true;
+	pub const MinimumDeposit: Balance = 1_000 * UNITS;
+	/// Delay between an approved referendum and its enactment, e.g. a runtime upgrade.
+	pub const EnactmentPeriod: BlockNumber = DAYS;
+	pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+	pub const PreimageByteDeposit: Balance = CENTS;
+	pub const MaxVotes: u32 = 100;
+	pub const MaxProposals: u32 = 100;
+}
+
+/// Conviction voting locks are placed on `Balances` and are therefore bounded by its `MaxLocks`.
+impl pallet_democracy::Config for Runtime {
+	type Proposal = Call;
+	type Event = Event;
+	type Currency = Balances;
+	type EnactmentPeriod = EnactmentPeriod;
+	type LaunchPeriod = LaunchPeriod;
+	type VotingPeriod = VotingPeriod;
+	type MinimumDeposit = MinimumDeposit;
+	/// A straight majority of the council can decide what their next motion is.
+	type ExternalOrigin = MoreThanHalfCouncil;
+	/// A super-majority can have the next scheduled referendum be a straight majority-carries vote.
+	type ExternalMajorityOrigin = ThreeQuartersCouncil;
+	/// A unanimous council can have the next scheduled referendum be a straight default-carries
+	/// (NTB) vote.
+	type ExternalDefaultOrigin = AllCouncil;
+	/// Two thirds of the technical committee can have an ExternalMajority/ExternalDefault vote
+	/// be tabled immediately and with a shorter voting/enactment period.
+	type FastTrackOrigin = TwoThirdsTechnicalCommittee;
+	type InstantOrigin = AllTechnicalCommittee;
+	type InstantAllowed = InstantAllowed;
+	type FastTrackVotingPeriod = FastTrackVotingPeriod;
+	// To cancel a proposal which has been passed, 2/3 of the council must agree to it.
+	type CancellationOrigin = EnsureRootOrTwoThirdsCouncil;
+	// To cancel a proposal before it has been passed, the technical committee must be unanimous or
+	// Root must agree.
+	type CancelProposalOrigin = EnsureOneOf<AccountId, EnsureRoot<AccountId>, AllTechnicalCommittee>;
+	type BlacklistOrigin = EnsureRoot<AccountId>;
+	// Any single technical committee member may veto a coming council proposal, however they can
+	// only do it once and it lasts only for the cooloff period.
+	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
+	type CooloffPeriod = CooloffPeriod;
+	type PreimageByteDeposit = PreimageByteDeposit;
+	type OperationalPreimageOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+	type Slash = Treasury;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type MaxVotes = MaxVotes;
+	type WeightInfo = ();
+	type MaxProposals = MaxProposals;
+}
+
 parameter_type_with_key! {
-	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
-		Zero::zero()
+	pub ExistentialDeposits: |currency_id: AssetId| -> Balance {
+		AssetDetails::existential_deposit(*currency_id)
 	};
 }
 
@@ -297,7 +546,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = AssetId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = ();
+	type OnDust = Duster;
 	type MaxLocks = MaxLocks;
 }
 
@@ -309,12 +558,215 @@ impl orml_currencies::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const DustingReward: Balance = CENTS;
+}
+
+impl pallet_duster::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type CurrencyId = AssetId;
+	type MultiCurrency = Currencies;
+	type MinCurrencyDeposits = ExistentialDeposits;
+	type NativeCurrencyId = NativeAssetId;
+	type Reward = DustingReward;
+	type DustAccount = TreasuryAccount;
+	type RewardAccount = TreasuryAccount;
+	type ProtectedAccounts = ProtectedAccounts;
+	type WhitelistUpdateOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type WeightInfo = pallet_duster::weights::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100 * UNITS;
+}
+
+/// Vesting of BSX. The vesting lock is placed on `Balances`, so it counts against `MaxLocks`.
+impl orml_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type MinVestedTransfer = MinVestedTransfer;
+	type VestedTransferOrigin = EnsureSigned<AccountId>;
+	type WeightInfo = ();
+}
+
+impl pallet_utility::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// One storage item; registration with all standard fields set is 258 bytes.
+	pub const BasicDeposit: Balance = deposit(1, 258);
+	// Additional field of 66 bytes.
+	pub const FieldDeposit: Balance = deposit(0, 66);
+	// One storage item; sub-account name of 53 bytes.
+	pub const SubAccountDeposit: Balance = deposit(1, 53);
+	pub const MaxSubAccounts: u32 = 100;
+	pub const MaxAdditionalFields: u32 = 100;
+	pub const MaxRegistrars: u32 = 20;
+}
+
+/// Identities of collators, asset issuers and liquidity providers. Deposits are reserved in BSX and
+/// each of them is above `ExistentialDeposit`.
+impl pallet_identity::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BasicDeposit = BasicDeposit;
+	type FieldDeposit = FieldDeposit;
+	type SubAccountDeposit = SubAccountDeposit;
+	type MaxSubAccounts = MaxSubAccounts;
+	type MaxAdditionalFields = MaxAdditionalFields;
+	type MaxRegistrars = MaxRegistrars;
+	type Slashed = Treasury;
+	type ForceOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type RegistrarOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+	pub const DepositBase: Balance = deposit(1, 88);
+	// Additional storage item size of 32 bytes.
+	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MaxSignatories: u16 = 100;
+}
+
+impl pallet_multisig::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
+	type MaxSignatories = MaxSignatories;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const MaxProxies: u16 = 32;
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const MaxPending: u16 = 32;
+}
+
+/// The type used to represent the kinds of proxying allowed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub enum ProxyType {
+	/// All calls.
+	Any,
+	/// All calls except those moving funds to other accounts.
+	NonTransfer,
+	/// Trading on XYK and Exchange and selecting the fee currency.
+	Trading,
+	/// Governance calls.
+	Governance,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::NonTransfer => matches!(
+				c,
+				Call::System(..)
+					| Call::Timestamp(..)
+					| Call::Council(..)
+					| Call::TechnicalCommittee(..)
+					| Call::Democracy(..)
+					| Call::Treasury(..)
+					| Call::TreasuryAssets(..)
+					| Call::Session(..)
+					| Call::Vesting(orml_vesting::Call::claim(..))
+					| Call::XYK(..)
+					| Call::Exchange(..)
+					| Call::Router(..)
+					| Call::MultiTransactionPayment(..)
+					| Call::Utility(..)
+					| Call::Identity(..)
+					| Call::Proxy(..)
+					| Call::Multisig(..)
+			),
+			ProxyType::Trading => matches!(
+				c,
+				Call::XYK(..)
+					| Call::Exchange(..)
+					| Call::Router(..)
+					| Call::MultiTransactionPayment(..)
+					| Call::Utility(..)
+			),
+			ProxyType::Governance => matches!(
+				c,
+				Call::Council(..)
+					| Call::TechnicalCommittee(..)
+					| Call::Democracy(..)
+					| Call::Treasury(..)
+					| Call::TreasuryAssets(..)
+					| Call::Utility(..)
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = ();
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
 /// Basilisk Pallets configurations
 
 impl pallet_asset_registry::Config for Runtime {
 	type AssetId = AssetId;
 }
 
+parameter_types! {
+	pub const DefaultExistentialDeposit: Balance = 1_000;
+}
+
+impl pallet_asset_details::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type RegistryOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type NativeAssetId = NativeAssetId;
+	type NativeExistentialDeposit = ExistentialDeposit;
+	type DefaultExistentialDeposit = DefaultExistentialDeposit;
+	type AssetLocation = xcm::v0::MultiLocation;
+	type ReservedLocations = xcm_config::AssetReservedLocations;
+	type Currency = Currencies;
+	type UnknownBalances = xcm_config::UnknownTokensBalances;
+	type WeightInfo = pallet_asset_details::weights::BasiliskWeight<Runtime>;
+}
+
 parameter_types! {
 	pub ExchangeFee: fee::Fee = fee::Fee::default();
 }
@@ -322,7 +774,7 @@ parameter_types! {
 impl pallet_xyk::Config for Runtime {
 	type Event = Event;
 	type AssetPairAccountId = pallet_xyk::AssetPairAccountId<Self>;
-	type Currency = Currencies;
+	type Currency = PoolCurrency;
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = pallet_xyk::weights::HydraWeight<Runtime>;
 	type GetExchangeFee = ExchangeFee;
@@ -341,17 +793,38 @@ impl pallet_faucet::Config for Runtime {
 	type Currency = Currencies;
 }
 
+parameter_types! {
+	/// Assets through which trade routes are searched, the assets most pools are expected to have.
+	pub RouterConnectorAssets: Vec<AssetId> = vec![CORE_ASSET_ID, xcm_config::RelayChainAssetId::get()];
+	pub const MaxRouteLength: u32 = 3;
+}
+
+impl pallet_router::Config for Runtime {
+	type Event = Event;
+	type AMMPool = XYK;
+	type Currency = Currencies;
+	type ExchangeFee = ExchangeFee;
+	type ConnectorAssets = RouterConnectorAssets;
+	type MaxRouteLength = MaxRouteLength;
+	type WeightInfo = pallet_router::weights::BasiliskWeight<Runtime>;
+}
+
 /// Parachain Config
 
+parameter_types! {
+	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
+	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
+}
+
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type Event = Event;
-	type OnValidationData = ();
+	type OnValidationData = RelayChainInfo;
 	type SelfParaId = ParachainInfo;
-	type OutboundXcmpMessageSource = ();
-	type DmpMessageHandler = ();
-	type ReservedDmpWeight = ();
-	type XcmpMessageHandler = ();
-	type ReservedXcmpWeight = ();
+	type OutboundXcmpMessageSource = XcmpQueue;
+	type DmpMessageHandler = DmpQueue;
+	type ReservedDmpWeight = ReservedDmpWeight;
+	type XcmpMessageHandler = XcmpQueue;
+	type ReservedXcmpWeight = ReservedXcmpWeight;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -360,38 +833,152 @@ impl pallet_aura::Config for Runtime {
 
 impl parachain_info::Config for Runtime {}
 
+parameter_types! {
+	pub const UpgradeDelay: BlockNumber = DAYS;
+}
+
+impl pallet_runtime_upgrade::Config for Runtime {
+	type Event = Event;
+	type AuthorizeOrigin = EnsureRootOrTwoThirdsCouncil;
+	type UpgradeDelay = UpgradeDelay;
+	type WeightInfo = pallet_runtime_upgrade::weights::BasiliskWeight<Runtime>;
+}
+
+impl pallet_relaychain_info::Config for Runtime {
+	type WeightInfo = pallet_relaychain_info::weights::BasiliskWeight<Runtime>;
+}
+
 impl cumulus_pallet_aura_ext::Config for Runtime {}
 
+/// Collator selection
+
+parameter_types! {
+	pub const UncleGenerations: u32 = 0;
+}
+
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type UncleGenerations = UncleGenerations;
+	type FilterUncle = ();
+	type EventHandler = (CollatorSelection, BlockReward);
+}
+
+parameter_types! {
+	pub const SessionPeriod: BlockNumber = 6 * HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+	pub const DisabledValidatorsThreshold: Perbill = Perbill::from_percent(33);
+}
+
+impl pallet_session::Config for Runtime {
+	type Event = Event;
+	type ValidatorId = <Self as frame_system::Config>::AccountId;
+	// we don't have stash and controller, thus we don't need the convert as well.
+	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type SessionManager = CollatorSelection;
+	// Essentially just Aura, but lets be pedantic.
+	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = SessionKeys;
+	type DisabledValidatorsThreshold = DisabledValidatorsThreshold;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PotId: PalletId = PalletId(*b"PotStake");
+	pub const MaxCandidates: u32 = 20;
+	pub const MaxInvulnerables: u32 = 10;
+	/// Bond reserved by a collator candidate.
+	pub const CandidacyBond: Balance = 10_000 * UNITS;
+	/// Number of bonded candidates selected as collators next to the invulnerables.
+	pub const DesiredCandidates: u32 = 4;
+}
+
+impl pallet_collator_selection::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type UpdateOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type PotId = PotId;
+	type MaxCandidates = MaxCandidates;
+	type MaxInvulnerables = MaxInvulnerables;
+	// should be a multiple of session or things will get inconsistent
+	type KickThreshold = SessionPeriod;
+	type ValidatorId = <Self as frame_system::Config>::AccountId;
+	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+	type ValidatorRegistration = Session;
+	type WeightInfo = ();
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
+//
+// Pallet indices are part of the encoding of calls and events, so they are pinned and a removed pallet's
+// index is never reused. Hooks still run in declaration order.
 construct_runtime!(
 	pub enum Runtime where
 		Block = Block,
 		NodeBlock = opaque::Block,
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
-		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Call, Storage},
-		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
-		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>} = 0,
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 3,
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage} = 4,
+		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 5,
+
+		// Governance
+		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 20,
+		TechnicalCommittee: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 21,
+		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 23,
+		Democracy: pallet_democracy::{Pallet, Call, Storage, Config, Event<T>} = 24,
+		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 25,
+		TransactionPause: pallet_transaction_pause::{Pallet, Call, Storage, Event} = 26,
+		TreasuryAssets: pallet_treasury_assets::{Pallet, Call, Event<T>} = 27,
 
 		// Parachain
-		ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Storage, Inherent, Event<T>, ValidateUnsigned},
-		ParachainInfo: parachain_info::{Pallet, Storage, Config},
-		Aura: pallet_aura::{Pallet, Config<T>},
-		AuraExt: cumulus_pallet_aura_ext::{Pallet, Config},
+		ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Storage, Inherent, Event<T>, ValidateUnsigned} = 6,
+		ParachainInfo: parachain_info::{Pallet, Storage, Config} = 7,
+		RelayChainInfo: pallet_relaychain_info::{Pallet, Call, Storage, Inherent} = 30,
+		RuntimeUpgrade: pallet_runtime_upgrade::{Pallet, Call, Storage, Event<T>} = 31,
+
+		// XCM
+		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 40,
+		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 41,
+		PolkadotXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin} = 42,
+		CumulusXcm: cumulus_pallet_xcm::{Pallet, Call, Event<T>, Origin} = 43,
+		HrmpChannels: pallet_hrmp_channels::{Pallet, Call, Storage, Event} = 44,
+		RelayReserve: pallet_relay_reserve::{Pallet, Call, Event<T>} = 45,
+
+		// Collator support. The order of these pallets is important: authorship must note the author
+		// before the session rotates, and the session must be initialized before Aura.
+		Authorship: pallet_authorship::{Pallet, Call, Storage} = 50,
+		CollatorSelection: pallet_collator_selection::{Pallet, Call, Storage, Event<T>, Config<T>} = 51,
+		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 52,
+		Aura: pallet_aura::{Pallet, Config<T>} = 8,
+		AuraExt: cumulus_pallet_aura_ext::{Pallet, Config} = 9,
 
 		// ORML related modules
-		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>},
-		Currencies: orml_currencies::{Pallet, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>, Config<T>} = 10,
+		Currencies: orml_currencies::{Pallet, Call, Event<T>} = 11,
+		Duster: pallet_duster::{Pallet, Call, Storage, Config<T>, Event<T>} = 60,
+		Vesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 61,
+		XTokens: orml_xtokens::{Pallet, Storage, Call, Event<T>} = 62,
+		UnknownTokens: orml_unknown_tokens::{Pallet, Storage, Event} = 63,
+
+		// Account management
+		Utility: pallet_utility::{Pallet, Call, Event} = 70,
+		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 71,
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 72,
+		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 73,
 
 		// Basilisk related modules
-		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>},
-		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>},
-		Exchange: pallet_exchange::{Pallet, Call, Storage, Event<T>},
-		Faucet: pallet_faucet::{Pallet, Call, Storage, Config, Event<T>},
-		MultiTransactionPayment: pallet_transaction_multi_payment::{Pallet, Call, Storage, Event<T>},
+		AssetRegistry: pallet_asset_registry::{Pallet, Call, Storage, Config<T>} = 12,
+		AssetDetails: pallet_asset_details::{Pallet, Call, Storage, Config<T>, Event<T>} = 80,
+		XYK: pallet_xyk::{Pallet, Call, Storage, Event<T>} = 13,
+		Exchange: pallet_exchange::{Pallet, Call, Storage, Event<T>} = 14,
+		Faucet: pallet_faucet::{Pallet, Call, Storage, Config, Event<T>} = 15,
+		MultiTransactionPayment: pallet_transaction_multi_payment::{Pallet, Call, Storage, Event<T>} = 16,
+		XcmTrade: pallet_xcm_trade::{Pallet, Call, Event} = 81,
+		Router: pallet_router::{Pallet, Call, Event<T>} = 82,
 	}
 );
 
@@ -414,14 +1001,21 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_transaction_pause::CheckTransactionPause<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	(migrations::SeedCollatorsFromAura, migrations::RemoveRandomnessCollectiveFlip),
+>;
 
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
@@ -530,6 +1124,67 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl vesting_runtime_api::VestingApi<Block, AccountId, Balance> for Runtime {
+		fn claimable(who: AccountId) -> Balance {
+			let now = System::block_number();
+			let still_locked = Vesting::vesting_schedules(&who)
+				.iter()
+				.fold(Balance::default(), |acc, schedule| acc.saturating_add(schedule.locked_amount(now)));
+			let locked = Balances::locks(&who)
+				.iter()
+				.find(|lock| lock.id == orml_vesting::VESTING_LOCK_ID)
+				.map(|lock| lock.amount)
+				.unwrap_or_default();
+
+			locked.saturating_sub(still_locked)
+		}
+	}
+
+	impl identity_runtime_api::IdentityApi<Block, AccountId> for Runtime {
+		fn display_name(who: AccountId) -> Option<Vec<u8>> {
+			let display = |who: &AccountId| {
+				Identity::identity(who).and_then(|registration| match registration.info.display {
+					pallet_identity::Data::Raw(name) => Some(name),
+					_ => None,
+				})
+			};
+
+			display(&who).or_else(|| {
+				let (parent, sub_name) = Identity::super_of(&who)?;
+				let mut name = display(&parent)?;
+				if let pallet_identity::Data::Raw(sub_name) = sub_name {
+					name.push(b'/');
+					name.extend(sub_name);
+				}
+				Some(name)
+			})
+		}
+	}
+
+	impl relaychain_info_runtime_api::RelayChainInfoApi<
+		Block,
+		cumulus_primitives_core::relay_chain::BlockNumber,
+		cumulus_primitives_core::relay_chain::Hash,
+	> for Runtime {
+		fn relay_parent_number() -> cumulus_primitives_core::relay_chain::BlockNumber {
+			RelayChainInfo::relay_parent_number()
+		}
+
+		fn relay_parent_storage_root() -> cumulus_primitives_core::relay_chain::Hash {
+			RelayChainInfo::relay_parent_storage_root()
+		}
+	}
+
+	impl router_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn best_sell_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)> {
+			Router::best_sell_route(asset_in, asset_out, amount)
+		}
+
+		fn best_buy_route(asset_in: AssetId, asset_out: AssetId, amount: Balance) -> Option<(Vec<AssetId>, Balance)> {
+			Router::best_buy_route(asset_in, asset_out, amount)
+		}
+	}
+
 	impl xyk_rpc::XYKApi<
 		Block,
 		AccountId,
@@ -594,6 +1249,16 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, exchange, ExchangeBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, asset_details, AssetDetails);
+			add_benchmark!(params, batches, duster, Duster);
+			add_benchmark!(params, batches, transaction_pause, TransactionPause);
+			add_benchmark!(params, batches, treasury_assets, TreasuryAssets);
+			add_benchmark!(params, batches, relaychain_info, RelayChainInfo);
+			add_benchmark!(params, batches, xcm_trade, XcmTrade);
+			add_benchmark!(params, batches, hrmp_channels, HrmpChannels);
+			add_benchmark!(params, batches, runtime_upgrade, RuntimeUpgrade);
+			add_benchmark!(params, batches, relay_reserve, RelayReserve);
+			add_benchmark!(params, batches, router, Router);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
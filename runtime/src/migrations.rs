@@ -0,0 +1,83 @@
+//! Storage migrations of the runtime.
+//!
+//! The migrations are one-off: they only run on the upgrade to `MIGRATIONS_SPEC_VERSION` and are
+//! to be removed from `Executive`, together with this module, once that upgrade is enacted.
+
+use crate::{AccountId, Aura, CandidacyBond, DesiredCandidates, Runtime, Session, SessionKeys, VERSION};
+use codec::Encode;
+use frame_support::{
+	storage::migration::put_storage_value,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::{constants::RocksDbWeight, Weight},
+	StorageHasher, Twox64Concat,
+};
+use sp_core::{crypto::key_types::AURA, hashing::twox_128, sr25519};
+use sp_std::prelude::*;
+
+/// Spec version whose upgrade runs the migrations of this module.
+pub const MIGRATIONS_SPEC_VERSION: u32 = 5;
+
+/// Whether the runtime being upgraded to is the one that runs the migrations.
+///
+/// `Executive` stores the new spec version before calling the migrations, so the upgrade is
+/// recognized by the version of this runtime.
+fn is_migrating_upgrade() -> bool {
+	VERSION.spec_version == MIGRATIONS_SPEC_VERSION
+}
+
+/// Seeds `Session` and `CollatorSelection` with the current Aura authorities.
+///
+/// The authorities were set at genesis before collators were selected by `CollatorSelection`.
+/// Without session keys and invulnerables the first session rotation after the upgrade would
+/// leave the chain without authors. The authorities become invulnerable collators with their
+/// Aura key as session key. Does nothing when the session already has validators or when the
+/// upgrade is not to `MIGRATIONS_SPEC_VERSION`.
+pub struct SeedCollatorsFromAura;
+impl OnRuntimeUpgrade for SeedCollatorsFromAura {
+	fn on_runtime_upgrade() -> Weight {
+		if !is_migrating_upgrade() {
+			return 0;
+		}
+		if !Session::validators().is_empty() {
+			return RocksDbWeight::get().reads(1);
+		}
+
+		let collators: Vec<(AccountId, SessionKeys)> = Aura::authorities()
+			.into_iter()
+			.map(|aura| (sr25519::Public::from(aura.clone()).into(), SessionKeys { aura }))
+			.collect();
+		let accounts: Vec<AccountId> = collators.iter().map(|(account, _)| account.clone()).collect();
+
+		// `pallet_session` does not expose its storage, the keys are written as `set_keys` would.
+		for (account, keys) in collators.iter() {
+			put_storage_value(b"Session", b"NextKeys", &Twox64Concat::hash(&account.encode()), keys);
+			let key_owner = (AURA, keys.aura.as_ref().to_vec());
+			put_storage_value(b"Session", b"KeyOwner", &Twox64Concat::hash(&key_owner.encode()), account);
+		}
+		put_storage_value(b"Session", b"Validators", &[], &accounts);
+		put_storage_value(b"Session", b"QueuedKeys", &[], &collators);
+
+		pallet_collator_selection::Invulnerables::<Runtime>::put(&accounts);
+		pallet_collator_selection::CandidacyBond::<Runtime>::put(CandidacyBond::get());
+		pallet_collator_selection::DesiredCandidates::<Runtime>::put(DesiredCandidates::get());
+
+		let writes = 2 * collators.len() as Weight + 5;
+		RocksDbWeight::get().reads_writes(2, writes)
+	}
+}
+
+/// Removes the storage of `RandomnessCollectiveFlip`, which was replaced by the relay chain
+/// randomness of `RelayChainInfo`. Does nothing when the upgrade is not to `MIGRATIONS_SPEC_VERSION`.
+pub struct RemoveRandomnessCollectiveFlip;
+impl OnRuntimeUpgrade for RemoveRandomnessCollectiveFlip {
+	fn on_runtime_upgrade() -> Weight {
+		if !is_migrating_upgrade() {
+			return 0;
+		}
+
+		frame_support::storage::unhashed::kill_prefix(&twox_128(b"RandomnessCollectiveFlip"));
+
+		// `RandomMaterial` and the pallet version.
+		RocksDbWeight::get().writes(2)
+	}
+}
@@ -0,0 +1,648 @@
+use crate::{
+	AccountId, Amount, AssetDetails, Aura, AuraId, AuthorFeeShare, Balance, Balances, BasicDeposit, BlockWeights, Call,
+	CollatorSelection, CouncilCollective, Currencies, DealWithFees, DesiredCandidates, DigestItem,
+	EnsureRootOrMoreThanHalfCouncil, EnsureRootOrTwoThirdsCouncil, ExistentialDeposit, ExtrinsicBaseWeight,
+	FieldDeposit, MaximumSchedulerWeight, MinimumMultiplier, MultipliedWeightToFee, Origin, PoolCurrency, ProxyType,
+	Runtime, Session, SubAccountDeposit, System, TargetBlockFullness, TargetTransferFee, TargetXYKTradeFee,
+	TechnicalCollective, TreasuryAccount, TreasuryAssets, UnknownTokens, WeightToFee, AVERAGE_ON_INITIALIZE_RATIO,
+	CORE_ASSET_ID, DAYS, MILLICENTS, UNITS, XYK,
+};
+use crate::migrations::{RemoveRandomnessCollectiveFlip, SeedCollatorsFromAura, MIGRATIONS_SPEC_VERSION};
+use crate::xcm_config::{
+	BsxLocation, DenyDisallowedTransact, DerivedSiblingAccount, MultiCurrencyTrader, RelayChainAssetId,
+	RelayTokenFeeRate, SelfLocation, XcmConfig,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::{migration::get_storage_value, unhashed},
+	traits::{All, Currency, EnsureOrigin, Filter, GenesisBuild, InstanceFilter, OnRuntimeUpgrade, OnUnbalanced},
+	weights::{DispatchClass, IdentityFee, Weight, WeightToFeePolynomial},
+	StorageHasher, StorageValue, Twox64Concat,
+};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use orml_xcm_support::UnknownAsset;
+use pallet_balances::WeightInfo as BalancesWeightInfo;
+use pallet_transaction_payment::Multiplier;
+use pallet_xyk::weights::WeightInfo as XYKWeightInfo;
+use primitives::{traits::AMM, AssetPair, Price};
+use sp_consensus_aura::AURA_ENGINE_ID;
+use sp_core::{crypto::key_types::AURA, hashing::twox_128, sr25519};
+use sp_runtime::{assert_eq_error_rate, traits::Convert, FixedPointNumber};
+use xcm::v0::{
+	Error as XcmError, Junction::*, MultiAsset, MultiLocation, MultiLocation::*, NetworkId, Order, OriginKind, Xcm,
+};
+use xcm_builder::AllowUnpaidExecutionFrom;
+use xcm_executor::{
+	traits::{Convert as XcmConvert, FilterAssetLocation, ShouldExecute, WeightTrader},
+	Assets,
+};
+
+type FeeMultiplierUpdate = <Runtime as pallet_transaction_payment::Config>::FeeMultiplierUpdate;
+
+fn run_with_system_weight<F>(w: Weight, mut assertions: F)
+where
+	F: FnMut(),
+{
+	let mut t: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap()
+		.into();
+	t.execute_with(|| {
+		System::set_block_limits(w, 0);
+		assertions()
+	});
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+
+	pallet_asset_registry::GenesisConfig::<Runtime> {
+		core_asset_id: CORE_ASSET_ID,
+		asset_ids: vec![(b"hKSM".to_vec(), 1), (b"hDOT".to_vec(), 2)],
+		next_asset_id: 3,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn max_normal() -> Weight {
+	BlockWeights::get()
+		.get(DispatchClass::Normal)
+		.max_total
+		.unwrap_or_else(|| BlockWeights::get().max_block)
+}
+
+#[test]
+fn multiplier_can_grow_from_zero() {
+	let minimum_multiplier = MinimumMultiplier::get();
+	let target = TargetBlockFullness::get() * max_normal();
+	// if the min is too small, then this will not change, and we are doomed forever.
+	// the weight is 1/100th bigger than target.
+	run_with_system_weight(target * 101 / 100, || {
+		let next = FeeMultiplierUpdate::convert(minimum_multiplier);
+		assert!(next > minimum_multiplier, "{:?} !>= {:?}", next, minimum_multiplier);
+	})
+}
+
+#[test]
+fn multiplier_grows_on_sustained_full_blocks() {
+	run_with_system_weight(max_normal(), || {
+		let mut multiplier = Multiplier::one();
+		for _ in 0..DAYS {
+			let next = FeeMultiplierUpdate::convert(multiplier);
+			assert!(next > multiplier, "{:?} !> {:?}", next, multiplier);
+			multiplier = next;
+		}
+		// a day of full blocks makes the fees noticeably more expensive
+		assert!(multiplier > Multiplier::saturating_from_rational(13, 10), "{:?}", multiplier);
+	})
+}
+
+#[test]
+fn multiplier_decreases_on_sustained_empty_blocks() {
+	run_with_system_weight(0, || {
+		let mut multiplier = Multiplier::one();
+		for _ in 0..DAYS {
+			let next = FeeMultiplierUpdate::convert(multiplier);
+			assert!(next < multiplier, "{:?} !< {:?}", next, multiplier);
+			multiplier = next;
+		}
+		assert!(multiplier >= MinimumMultiplier::get());
+	})
+}
+
+#[test]
+fn multiplier_does_not_drop_below_minimum() {
+	let minimum_multiplier = MinimumMultiplier::get();
+	run_with_system_weight(0, || {
+		let next = FeeMultiplierUpdate::convert(minimum_multiplier);
+		assert_eq!(next, minimum_multiplier);
+	})
+}
+
+#[test]
+fn multi_payment_fee_is_scaled_by_multiplier() {
+	run_with_system_weight(0, || {
+		let weight: Weight = 1_000_000;
+		assert_eq!(
+			MultipliedWeightToFee::<IdentityFee<Balance>>::calc(&weight),
+			IdentityFee::<Balance>::calc(&weight)
+		);
+
+		let multiplier = Multiplier::saturating_from_integer(2);
+		pallet_transaction_payment::NextFeeMultiplier::put(multiplier);
+		assert_eq!(
+			MultipliedWeightToFee::<IdentityFee<Balance>>::calc(&weight),
+			2 * IdentityFee::<Balance>::calc(&weight)
+		);
+	})
+}
+
+#[test]
+fn fees_are_split_between_author_and_treasury() {
+	let author = AccountId::from([1u8; 32]);
+
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+	pallet_aura::GenesisConfig::<Runtime> {
+		authorities: vec![sr25519::Public::from_raw([1u8; 32]).into()],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		SeedCollatorsFromAura::on_runtime_upgrade();
+		System::deposit_log(DigestItem::PreRuntime(AURA_ENGINE_ID, 0u64.encode()));
+
+		let fees = Balances::issue(10 * UNITS);
+		let tips = Balances::issue(UNITS);
+		DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+		let author_fee = AuthorFeeShare::get() * (10 * UNITS);
+		assert_eq!(Balances::free_balance(&author), author_fee + UNITS);
+		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), 10 * UNITS - author_fee);
+	});
+}
+
+#[test]
+fn fees_go_to_treasury_when_author_is_unknown() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let fees = Balances::issue(10 * UNITS);
+		let tips = Balances::issue(UNITS);
+		DealWithFees::on_unbalanceds(vec![fees, tips].into_iter());
+
+		assert_eq!(Balances::free_balance(&AccountId::default()), 0);
+		assert_eq!(Balances::free_balance(&TreasuryAccount::get()), 11 * UNITS);
+	});
+}
+
+#[test]
+fn weight_to_fee_meets_transfer_and_trade_targets() {
+	let fee = |weight: Weight| WeightToFee::calc(&ExtrinsicBaseWeight::get()) + WeightToFee::calc(&weight);
+	let transfer_fee = fee(<Runtime as pallet_balances::Config>::WeightInfo::transfer());
+	let trade_fee = fee(<Runtime as pallet_xyk::Config>::WeightInfo::sell());
+
+	assert!(transfer_fee + MILLICENTS >= TargetTransferFee::get(), "{:?}", transfer_fee);
+	assert!(trade_fee + MILLICENTS >= TargetXYKTradeFee::get(), "{:?}", trade_fee);
+	assert_eq_error_rate!(
+		trade_fee - transfer_fee,
+		TargetXYKTradeFee::get() - TargetTransferFee::get(),
+		MILLICENTS
+	);
+}
+
+#[test]
+fn scheduler_fits_into_on_initialize_weight() {
+	assert!(MaximumSchedulerWeight::get() <= AVERAGE_ON_INITIALIZE_RATIO * BlockWeights::get().max_block);
+}
+
+#[test]
+fn identity_deposits_are_above_existential_deposit() {
+	assert!(BasicDeposit::get() >= ExistentialDeposit::get());
+	assert!(FieldDeposit::get() >= ExistentialDeposit::get());
+	assert!(SubAccountDeposit::get() >= ExistentialDeposit::get());
+}
+
+#[test]
+fn migrations_run_on_the_current_spec_version() {
+	assert_eq!(
+		crate::VERSION.spec_version,
+		MIGRATIONS_SPEC_VERSION,
+		"the migrations were enacted by an earlier upgrade, remove them from `Executive`"
+	);
+}
+
+#[test]
+fn randomness_collective_flip_storage_is_removed() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		let key = [twox_128(b"RandomnessCollectiveFlip"), twox_128(b"RandomMaterial")].concat();
+		unhashed::put(&key, &vec![[1u8; 32]]);
+
+		RemoveRandomnessCollectiveFlip::on_runtime_upgrade();
+
+		assert!(!unhashed::exists(&key));
+	});
+}
+
+#[test]
+fn collators_are_seeded_from_aura_authorities() {
+	let aura_ids: Vec<AuraId> = vec![
+		sr25519::Public::from_raw([1u8; 32]).into(),
+		sr25519::Public::from_raw([2u8; 32]).into(),
+	];
+	let accounts = vec![AccountId::from([1u8; 32]), AccountId::from([2u8; 32])];
+
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+	pallet_aura::GenesisConfig::<Runtime> {
+		authorities: aura_ids.clone(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		SeedCollatorsFromAura::on_runtime_upgrade();
+
+		assert_eq!(Session::validators(), accounts);
+		assert_eq!(Session::queued_keys().len(), 2);
+		assert_eq!(CollatorSelection::invulnerables(), accounts);
+		assert_eq!(CollatorSelection::desired_candidates(), DesiredCandidates::get());
+		let key_owner = (AURA, aura_ids[0].as_ref().to_vec());
+		assert_eq!(
+			get_storage_value::<AccountId>(b"Session", b"KeyOwner", &Twox64Concat::hash(&key_owner.encode())),
+			Some(accounts[0].clone())
+		);
+
+		// the authors are kept across session rotations
+		Session::rotate_session();
+		Session::rotate_session();
+		assert_eq!(Session::validators(), accounts);
+		assert_eq!(Aura::authorities(), aura_ids);
+
+		// already seeded, invulnerables changed by governance are not overwritten
+		pallet_collator_selection::Invulnerables::<Runtime>::put(vec![accounts[0].clone()]);
+		SeedCollatorsFromAura::on_runtime_upgrade();
+		assert_eq!(CollatorSelection::invulnerables(), vec![accounts[0].clone()]);
+	});
+}
+
+#[test]
+fn council_origins_require_their_proportion_of_the_council() {
+	let council = |yes, total| -> Origin {
+		pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, total).into()
+	};
+	let technical_committee = |yes, total| -> Origin {
+		pallet_collective::RawOrigin::<AccountId, TechnicalCollective>::Members(yes, total).into()
+	};
+
+	assert!(EnsureRootOrMoreThanHalfCouncil::try_origin(Origin::root()).is_ok());
+	assert!(EnsureRootOrMoreThanHalfCouncil::try_origin(council(2, 3)).is_ok());
+	assert!(EnsureRootOrMoreThanHalfCouncil::try_origin(council(1, 2)).is_err());
+	assert!(EnsureRootOrMoreThanHalfCouncil::try_origin(technical_committee(3, 3)).is_err());
+	assert!(EnsureRootOrMoreThanHalfCouncil::try_origin(Origin::signed(AccountId::from([1u8; 32]))).is_err());
+
+	assert!(EnsureRootOrTwoThirdsCouncil::try_origin(Origin::root()).is_ok());
+	assert!(EnsureRootOrTwoThirdsCouncil::try_origin(council(2, 3)).is_ok());
+	assert!(EnsureRootOrTwoThirdsCouncil::try_origin(council(3, 5)).is_err());
+}
+
+#[test]
+fn pool_currency_does_not_leave_native_dust_on_pool_account() {
+	let alice = AccountId::from([1u8; 32]);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::update_balance(CORE_ASSET_ID, &alice, (1_000 * UNITS) as Amount));
+		assert_ok!(Currencies::update_balance(1, &alice, (1_000 * UNITS) as Amount));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(alice.clone()),
+			CORE_ASSET_ID,
+			1,
+			100 * UNITS,
+			Price::saturating_from_integer(1)
+		));
+
+		let pool = XYK::get_pair_id(&AssetPair {
+			asset_in: CORE_ASSET_ID,
+			asset_out: 1,
+		});
+		let reserve = Currencies::free_balance(CORE_ASSET_ID, &pool);
+
+		assert_noop!(
+			PoolCurrency::transfer(CORE_ASSET_ID, &pool, &alice, reserve - ExistentialDeposit::get() + 1),
+			PoolCurrency::NATIVE_DUST
+		);
+		assert_ok!(PoolCurrency::transfer(
+			CORE_ASSET_ID,
+			&pool,
+			&alice,
+			reserve - ExistentialDeposit::get()
+		));
+		assert_ok!(PoolCurrency::transfer(CORE_ASSET_ID, &pool, &alice, ExistentialDeposit::get()));
+		assert_eq!(Currencies::free_balance(CORE_ASSET_ID, &pool), 0);
+
+		// only native balances of pool accounts are protected
+		let other_reserve = Currencies::free_balance(1, &pool);
+		assert_ok!(PoolCurrency::transfer(1, &pool, &alice, other_reserve - 1));
+	});
+}
+
+#[test]
+fn council_can_spend_non_native_treasury_assets() {
+	let alice = AccountId::from([1u8; 32]);
+	let council = |yes, total| -> Origin {
+		pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, total).into()
+	};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(1, &TreasuryAccount::get(), 100 * UNITS));
+
+		assert_noop!(
+			TreasuryAssets::spend(council(1, 2), 1, alice.clone(), 40 * UNITS),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TreasuryAssets::spend(council(2, 3), 1, alice.clone(), 40 * UNITS));
+
+		assert_eq!(Currencies::free_balance(1, &alice), 40 * UNITS);
+		assert_eq!(Currencies::free_balance(1, &TreasuryAccount::get()), 60 * UNITS);
+	});
+}
+
+#[test]
+fn non_transfer_proxy_covers_governance_calls() {
+	let alice = AccountId::from([1u8; 32]);
+	let spend = Call::TreasuryAssets(pallet_treasury_assets::Call::spend(1, alice, 40 * UNITS));
+
+	assert!(ProxyType::Governance.filter(&spend));
+	assert!(ProxyType::NonTransfer.filter(&spend));
+	assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Governance));
+}
+
+fn ksm_location() -> MultiLocation {
+	X2(Parent, Parachain(1000))
+}
+
+fn fungible(id: MultiLocation, amount: Balance) -> MultiAsset {
+	MultiAsset::ConcreteFungible { id, amount }
+}
+
+/// Test externalities with hKSM located on parachain 1000 and an hKSM/BSX pool with price 1.
+fn new_xcm_test_ext() -> sp_io::TestExternalities {
+	let alice = AccountId::from([1u8; 32]);
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(AssetDetails::register(Origin::root(), b"hKSM".to_vec(), 1_000));
+		assert_ok!(AssetDetails::set_location(Origin::root(), 1, ksm_location()));
+		assert_ok!(Currencies::update_balance(CORE_ASSET_ID, &alice, (1_000 * UNITS) as Amount));
+		assert_ok!(Currencies::update_balance(1, &alice, (1_000 * UNITS) as Amount));
+		assert_ok!(XYK::create_pool(
+			Origin::signed(alice.clone()),
+			CORE_ASSET_ID,
+			1,
+			100 * UNITS,
+			Price::saturating_from_integer(1)
+		));
+	});
+	ext
+}
+
+const XCM_WEIGHT: Weight = 5_000_000_000;
+
+#[test]
+fn trader_buys_weight_at_spot_price_and_refunds_unused_weight() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+		assert!(fee > 0);
+
+		let mut trader = MultiCurrencyTrader::new();
+		let payment: Assets = vec![fungible(ksm_location(), 2 * fee)].into();
+		let unused = trader.buy_weight(XCM_WEIGHT, payment).unwrap();
+		assert_eq!(
+			unused.fungible_assets_iter().collect::<Vec<_>>(),
+			vec![fungible(ksm_location(), fee)]
+		);
+
+		let refund_fee = WeightToFee::calc(&(XCM_WEIGHT / 2));
+		assert_eq!(trader.refund_weight(XCM_WEIGHT / 2), fungible(ksm_location(), refund_fee));
+
+		// the kept payment is minted to the treasury
+		drop(trader);
+		assert_eq!(Currencies::free_balance(1, &TreasuryAccount::get()), fee - refund_fee);
+	});
+}
+
+#[test]
+fn trader_refunds_at_most_the_payment() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+
+		let mut trader = MultiCurrencyTrader::new();
+		let payment: Assets = vec![fungible(ksm_location(), fee)].into();
+		assert_ok!(trader.buy_weight(XCM_WEIGHT, payment));
+
+		assert_eq!(trader.refund_weight(2 * XCM_WEIGHT), fungible(ksm_location(), fee));
+		assert_eq!(trader.refund_weight(XCM_WEIGHT), MultiAsset::None);
+
+		drop(trader);
+		assert_eq!(Currencies::free_balance(1, &TreasuryAccount::get()), 0);
+	});
+}
+
+#[test]
+fn trader_accepts_relay_token_at_fee_rate_until_it_has_a_pool() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+		let amount = RelayTokenFeeRate::get().saturating_mul_int(fee);
+		assert_eq!(RelayChainAssetId::get(), 2);
+
+		let mut trader = MultiCurrencyTrader::new();
+		let payment: Assets = vec![fungible(X1(Parent), amount)].into();
+		let unused = trader.buy_weight(XCM_WEIGHT, payment).unwrap();
+		assert_eq!(unused.fungible_assets_iter().count(), 0);
+
+		let payment: Assets = vec![fungible(X1(Parent), amount - 1)].into();
+		assert_eq!(
+			MultiCurrencyTrader::new().buy_weight(XCM_WEIGHT, payment),
+			Err(XcmError::TooExpensive)
+		);
+
+		drop(trader);
+		assert_eq!(Currencies::free_balance(2, &TreasuryAccount::get()), amount);
+	});
+}
+
+#[test]
+fn trader_rejects_insufficient_or_unpriced_payment() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+		let buy = |payment: Vec<MultiAsset>| MultiCurrencyTrader::new().buy_weight(XCM_WEIGHT, payment.into());
+
+		// not enough of a pool asset
+		assert_eq!(buy(vec![fungible(ksm_location(), fee - 1)]), Err(XcmError::TooExpensive));
+		// unknown asset
+		assert_eq!(
+			buy(vec![fungible(X2(Parent, Parachain(3000)), 10 * fee)]),
+			Err(XcmError::TooExpensive)
+		);
+		// known asset without a pool
+		assert_ok!(AssetDetails::register(Origin::root(), b"hUSD".to_vec(), 1_000));
+		assert_ok!(AssetDetails::set_location(Origin::root(), 3, X2(Parent, Parachain(2000))));
+		assert_eq!(
+			buy(vec![fungible(X2(Parent, Parachain(2000)), 10 * fee)]),
+			Err(XcmError::TooExpensive)
+		);
+	});
+}
+
+#[test]
+fn trader_pays_for_a_message_with_a_single_asset() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+
+		let mut trader = MultiCurrencyTrader::new();
+		assert_ok!(trader.buy_weight(XCM_WEIGHT, vec![fungible(ksm_location(), fee)].into()));
+		assert_eq!(
+			trader.buy_weight(XCM_WEIGHT, vec![fungible(X1(Parent), 10 * fee)].into()),
+			Err(XcmError::TooExpensive)
+		);
+	});
+}
+
+#[test]
+fn trader_mints_bsx_fees_to_author_and_treasury() {
+	new_xcm_test_ext().execute_with(|| {
+		let fee = WeightToFee::calc(&XCM_WEIGHT);
+		let issuance = Balances::total_issuance();
+		let treasury = Balances::free_balance(&TreasuryAccount::get());
+
+		let mut trader = MultiCurrencyTrader::new();
+		assert_ok!(trader.buy_weight(XCM_WEIGHT, vec![fungible(BsxLocation::get(), fee)].into()));
+		drop(trader);
+
+		assert_eq!(Balances::total_issuance(), issuance + fee);
+		assert_eq!(
+			Balances::free_balance(&TreasuryAccount::get()),
+			treasury + fee - AuthorFeeShare::get() * fee
+		);
+	});
+}
+
+type TestBarrier = DenyDisallowedTransact<AllowUnpaidExecutionFrom<All<MultiLocation>>>;
+
+fn transact(call: Call) -> Xcm<Call> {
+	Xcm::Transact {
+		origin_type: OriginKind::SovereignAccount,
+		require_weight_at_most: 1_000_000_000,
+		call: call.encode().into(),
+	}
+}
+
+fn should_execute(origin: MultiLocation, message: Xcm<Call>) -> Result<(), ()> {
+	TestBarrier::should_execute(&origin, true, &message, 0, &mut 0)
+}
+
+#[test]
+fn barrier_allows_only_filtered_transact_from_other_chains() {
+	let sibling = X2(Parent, Parachain(2000));
+	let trade = Call::XcmTrade(pallet_xcm_trade::Call::sell(0, 1, CORE_ASSET_ID, UNITS, 0));
+	let transfer = Call::Balances(pallet_balances::Call::transfer(AccountId::from([2u8; 32]), UNITS));
+
+	assert_eq!(should_execute(sibling.clone(), transact(trade)), Ok(()));
+	assert_eq!(should_execute(sibling.clone(), transact(transfer.clone())), Err(()));
+	// the relay chain is trusted
+	assert_eq!(should_execute(X1(Parent), transact(transfer.clone())), Ok(()));
+	// but not the messages it relays
+	assert_eq!(
+		should_execute(
+			X1(Parent),
+			Xcm::RelayedFrom {
+				who: X1(Parachain(2000)),
+				message: Box::new(transact(transfer.clone())),
+			}
+		),
+		Err(())
+	);
+	// calls nested in a paid message are filtered as well
+	let nested = Xcm::WithdrawAsset {
+		assets: vec![fungible(X1(Parent), UNITS)],
+		effects: vec![Order::BuyExecution {
+			fees: MultiAsset::All,
+			weight: 0,
+			debt: 1_000_000_000,
+			halt_on_error: true,
+			xcm: vec![transact(transfer)],
+		}],
+	};
+	assert_eq!(should_execute(sibling, nested), Err(()));
+}
+
+#[test]
+fn barrier_rejects_undecodable_transact() {
+	let message = Xcm::Transact {
+		origin_type: OriginKind::SovereignAccount,
+		require_weight_at_most: 1_000_000_000,
+		call: vec![255u8, 255, 255].into(),
+	};
+	assert_eq!(should_execute(X2(Parent, Parachain(2000)), message), Err(()));
+}
+
+#[test]
+fn sibling_accounts_are_derived_per_parachain() {
+	let id = [7u8; 32];
+	let location = |para_id, network| X3(Parent, Parachain(para_id), AccountId32 { network, id });
+
+	let account = DerivedSiblingAccount::convert_ref(location(2000, NetworkId::Any)).unwrap();
+	assert_ne!(account, AccountId::from(id));
+	assert_eq!(
+		DerivedSiblingAccount::convert_ref(location(2000, NetworkId::Kusama)),
+		Ok(account.clone())
+	);
+	assert_ne!(DerivedSiblingAccount::convert_ref(location(2001, NetworkId::Any)), Ok(account));
+
+	assert!(DerivedSiblingAccount::convert_ref(X2(Parent, AccountId32 { network: NetworkId::Any, id })).is_err());
+	assert!(DerivedSiblingAccount::convert_ref(X1(AccountId32 { network: NetworkId::Any, id })).is_err());
+	assert!(DerivedSiblingAccount::convert_ref(X2(Parent, Parachain(2000))).is_err());
+}
+
+#[test]
+fn teleports_are_denied() {
+	let assets = vec![fungible(BsxLocation::get(), UNITS)];
+	assert!(!<Runtime as pallet_xcm::Config>::XcmTeleportFilter::filter(&(
+		X1(Parent),
+		assets.clone()
+	)));
+	assert!(!<XcmConfig as xcm_executor::Config>::IsTeleporter::filter_asset_location(
+		&assets[0],
+		&X1(Parent)
+	));
+}
+
+#[test]
+fn reserved_locations_can_not_be_set_for_assets() {
+	new_xcm_test_ext().execute_with(|| {
+		assert_ok!(AssetDetails::register(Origin::root(), b"hUSD".to_vec(), 1_000));
+
+		for location in vec![X1(Parent), SelfLocation::get(), BsxLocation::get(), X1(GeneralKey(vec![0]))] {
+			assert_noop!(
+				AssetDetails::set_location(Origin::root(), 3, location),
+				pallet_asset_details::Error::<Runtime>::LocationReserved
+			);
+		}
+
+		// the relay chain and sibling parachains are not reserved
+		assert_ok!(AssetDetails::set_location(Origin::root(), 3, X2(Parent, Parachain(2000))));
+		// setting the same location again does nothing
+		assert_ok!(AssetDetails::set_location(Origin::root(), 3, X2(Parent, Parachain(2000))));
+	});
+}
+
+#[test]
+fn unknown_tokens_are_claimed_once_the_asset_location_is_set() {
+	new_xcm_test_ext().execute_with(|| {
+		let alice = AccountId::from([1u8; 32]);
+		let usd = X2(Parent, Parachain(2000));
+		let owner = |network| {
+			X1(AccountId32 {
+				network,
+				id: alice.clone().into(),
+			})
+		};
+
+		assert_ok!(<UnknownTokens as UnknownAsset>::deposit(&fungible(usd.clone(), 5_000), &owner(NetworkId::Any)));
+		assert_ok!(<UnknownTokens as UnknownAsset>::deposit(&fungible(usd.clone(), 2_000), &owner(NetworkId::Kusama)));
+
+		assert_ok!(AssetDetails::register(Origin::root(), b"hUSD".to_vec(), 1_000));
+		assert_ok!(AssetDetails::set_location(Origin::root(), 3, usd.clone()));
+		assert_ok!(AssetDetails::claim_unknown_balance(Origin::signed(alice.clone()), 3));
+
+		assert_eq!(Currencies::free_balance(3, &alice), 7_000);
+		assert_eq!(UnknownTokens::concrete_fungible_balances(&owner(NetworkId::Any), &usd), 0);
+		assert_eq!(UnknownTokens::concrete_fungible_balances(&owner(NetworkId::Kusama), &usd), 0);
+	});
+}
@@ -0,0 +1,593 @@
+//! Cross-consensus messaging configuration.
+//!
+//! Messages from the relay chain are received through the DMP queue, messages from sibling
+//! parachains through the XCMP queue. Both are executed by `XcmExecutor<XcmConfig>`.
+
+use crate::impls::{DealWithFees, WeightToFee};
+use crate::{
+	AccountId, AssetDetails, AssetId, Balance, Balances, Call, Currencies, EnsureRootOrMoreThanHalfCouncil, Event,
+	Origin, ParachainInfo, ParachainSystem, Runtime, TreasuryAccount, UnknownTokens, XcmpQueue, CORE_ASSET_ID, XYK,
+};
+use codec::{Decode, Encode};
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	match_type, parameter_types,
+	traits::{All, Contains, Currency, EnsureOrigin, Filter, Get, OnUnbalanced},
+	weights::{Weight, WeightToFeePolynomial},
+};
+use orml_traits::MultiCurrency;
+use orml_xcm_support::{IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset, UnknownAsset};
+use polkadot_parachain::primitives::Sibling;
+use primitives::{traits::AMM, AssetPair};
+use sp_core::hashing::blake2_256;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{Convert, Zero},
+	FixedPointNumber, FixedU128,
+};
+use sp_std::{borrow::Borrow, marker::PhantomData, prelude::*};
+use xcm::v0::{
+	BodyId, Error as XcmError, Junction::*, MultiAsset, MultiLocation, MultiLocation::*, NetworkId, Order, Xcm,
+};
+use xcm_builder::{
+	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds,
+	LocationInverter, ParentAsSuperuser, ParentIsDefault, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation,
+	TakeWeightCredit, XcmPassthrough,
+};
+use xcm_executor::{
+	traits::{Convert as XcmConvert, ShouldExecute, WeightTrader},
+	Assets, XcmExecutor,
+};
+
+parameter_types! {
+	pub const RelayLocation: MultiLocation = X1(Parent);
+	pub const RelayNetwork: NetworkId = NetworkId::Any;
+	pub RelayChainOrigin: Origin = cumulus_pallet_xcm::Origin::Relay.into();
+	pub Ancestry: MultiLocation = X1(Parachain(ParachainInfo::parachain_id().into()));
+	/// Location of this chain as seen by the relay chain and sibling parachains.
+	pub SelfLocation: MultiLocation = X2(Parent, Parachain(ParachainInfo::parachain_id().into()));
+	/// Location of BSX. This chain is the reserve of BSX.
+	pub BsxLocation: MultiLocation = X3(
+		Parent,
+		Parachain(ParachainInfo::parachain_id().into()),
+		GeneralKey(CORE_ASSET_ID.encode())
+	);
+	/// Asset id of the relay chain token, registered as `hDOT`. The relay chain is its reserve.
+	pub const RelayChainAssetId: AssetId = 2;
+	/// Amount of the relay chain token charged per BSX of XCM fees while the relay chain token has
+	/// no XYK pool with BSX.
+	pub RelayTokenFeeRate: FixedU128 = FixedU128::saturating_from_rational(1, 10);
+}
+
+/// Converts a `MultiLocation` into a local `AccountId`.
+///
+/// Used to determine the owner of assets deposited by XCM and the origin of `Transact`.
+pub type LocationToAccountId = (
+	// The relay chain converts to the default `AccountId`.
+	ParentIsDefault<AccountId>,
+	// Sibling parachains convert to their sovereign account.
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	// `AccountId32` junctions alias directly to `AccountId`.
+	AccountId32Aliases<RelayNetwork, AccountId>,
+	// Accounts of sibling parachains convert to an account derived for them.
+	DerivedSiblingAccount,
+);
+
+/// Derives an account for an account of a sibling parachain,
+/// `X3(Parent, Parachain(_), AccountId32 { .. })`.
+///
+/// The derived account is controlled by the sibling parachain on behalf of its account and is
+/// distinct from the account with the same id on this chain.
+pub struct DerivedSiblingAccount;
+impl XcmConvert<MultiLocation, AccountId> for DerivedSiblingAccount {
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<AccountId, ()> {
+		match location.borrow() {
+			X3(Parent, Parachain(para_id), AccountId32 { id, .. }) => {
+				Ok((b"sibl/acc", para_id, id).using_encoded(blake2_256).into())
+			}
+			_ => Err(()),
+		}
+	}
+}
+
+/// Converts between `AssetId` and the location of the asset.
+///
+/// BSX is identified by `BsxLocation` and the relay chain token by `RelayLocation`, other assets by
+/// the location set in `AssetDetails`.
+pub struct CurrencyIdConvert;
+
+impl Convert<AssetId, Option<MultiLocation>> for CurrencyIdConvert {
+	fn convert(id: AssetId) -> Option<MultiLocation> {
+		match id {
+			CORE_ASSET_ID => Some(BsxLocation::get()),
+			id if id == RelayChainAssetId::get() => Some(RelayLocation::get()),
+			_ => AssetDetails::asset_location(id),
+		}
+	}
+}
+
+impl Convert<MultiLocation, Option<AssetId>> for CurrencyIdConvert {
+	fn convert(location: MultiLocation) -> Option<AssetId> {
+		match &location {
+			X3(Parent, Parachain(para_id), GeneralKey(key))
+				if ParaId::from(*para_id) == ParachainInfo::parachain_id() =>
+			{
+				AssetId::decode(&mut &key[..]).ok().filter(|id| *id == CORE_ASSET_ID)
+			}
+			X1(Parent) => Some(RelayChainAssetId::get()),
+			_ => AssetDetails::location_asset(location),
+		}
+	}
+}
+
+impl Convert<MultiAsset, Option<AssetId>> for CurrencyIdConvert {
+	fn convert(asset: MultiAsset) -> Option<AssetId> {
+		match asset {
+			MultiAsset::ConcreteFungible { id, .. } => Self::convert(id),
+			_ => None,
+		}
+	}
+}
+
+/// Locations which `AssetDetails` does not accept for an asset.
+///
+/// The relay chain token is identified by `RelayLocation` already. Locations of this chain, either
+/// relative to it or through its parachain id, belong to assets of this chain.
+pub struct AssetReservedLocations;
+impl Contains<MultiLocation> for AssetReservedLocations {
+	fn contains(location: &MultiLocation) -> bool {
+		let para_id: u32 = ParachainInfo::parachain_id().into();
+		match (location.first(), location.at(1)) {
+			(Some(Parent), None) => true,
+			(Some(Parent), Some(Parachain(id))) => *id == para_id,
+			(Some(Parent), Some(_)) => false,
+			_ => true,
+		}
+	}
+}
+
+/// Balances of unknown assets `UnknownTokens` holds for accounts of this chain, claimed through
+/// `AssetDetails` once the location of the asset is set.
+///
+/// `UnknownTokens` holds deposits under the beneficiary location of the message, the balances of
+/// all networks of an `AccountId32` beneficiary are claimed.
+pub struct UnknownTokensBalances;
+impl UnknownTokensBalances {
+	const NETWORKS: [NetworkId; 3] = [NetworkId::Any, NetworkId::Kusama, NetworkId::Polkadot];
+
+	fn owner(who: &AccountId, network: NetworkId) -> MultiLocation {
+		X1(AccountId32 {
+			network,
+			id: who.clone().into(),
+		})
+	}
+}
+
+impl pallet_asset_details::UnknownBalances<AccountId, MultiLocation, Balance> for UnknownTokensBalances {
+	fn take(who: &AccountId, location: &MultiLocation) -> Balance {
+		Self::NETWORKS.iter().fold(Zero::zero(), |claimed: Balance, network| {
+			let owner = Self::owner(who, network.clone());
+			let amount = UnknownTokens::concrete_fungible_balances(&owner, location);
+			let asset = MultiAsset::ConcreteFungible {
+				id: location.clone(),
+				amount,
+			};
+			if amount.is_zero() || UnknownTokens::withdraw(&asset, &owner).is_err() {
+				return claimed;
+			}
+			claimed.saturating_add(amount)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn put(who: &AccountId, location: &MultiLocation, amount: Balance) {
+		let asset = MultiAsset::ConcreteFungible {
+			id: location.clone(),
+			amount,
+		};
+		UnknownTokens::deposit(&asset, &Self::owner(who, NetworkId::Any)).expect("unknown asset is deposited");
+	}
+}
+
+/// Converts an account into the `AccountId32` bytes used in locations.
+pub struct AccountId32Convert;
+impl Convert<AccountId, [u8; 32]> for AccountId32Convert {
+	fn convert(account_id: AccountId) -> [u8; 32] {
+		account_id.into()
+	}
+}
+
+/// Transacts all assets known to `CurrencyIdConvert` through `Currencies`.
+///
+/// Deposits of unknown assets are held by `UnknownTokens`.
+pub type LocalAssetTransactor = MultiCurrencyAdapter<
+	Currencies,
+	UnknownTokens,
+	IsNativeConcrete<AssetId, CurrencyIdConvert>,
+	AccountId,
+	LocationToAccountId,
+	AssetId,
+	CurrencyIdConvert,
+>;
+
+/// Converts an incoming XCM origin into a local `Origin` for dispatching `Transact`.
+pub type XcmOriginToTransactDispatchOrigin = (
+	// Signed origin of the sovereign account of the location.
+	SovereignSignedViaLocation<LocationToAccountId, Origin>,
+	// Native `Relay` origin for the relay chain.
+	RelayChainAsNative<RelayChainOrigin, Origin>,
+	// Native `SiblingParachain` origin for sibling parachains.
+	SiblingParachainAsNative<cumulus_pallet_xcm::Origin, Origin>,
+	// `Root` origin for the relay chain when it asks for superuser.
+	ParentAsSuperuser<Origin>,
+	// Signed origin of the same `AccountId32`.
+	SignedAccountId32AsNative<RelayNetwork, Origin>,
+	// XCM origin of the location, used by `XcmTrade` to respond to the sender.
+	XcmPassthrough<Origin>,
+);
+
+parameter_types! {
+	/// Conservative estimate of the weight of one XCM instruction.
+	pub const UnitWeightCost: Weight = 200_000_000;
+	/// Weight of the XCM that is executed on this chain before a transfer is sent.
+	pub const BaseXcmWeight: Weight = 100_000_000;
+}
+
+match_type! {
+	pub type ParentOrParentsUnitPlurality: impl Contains<MultiLocation> = {
+		X1(Parent) | X2(Parent, Plurality { id: BodyId::Unit, .. })
+	};
+}
+
+/// Calls which chains other than the relay chain may dispatch with `Transact`.
+pub struct RemoteCallFilter;
+impl Filter<Call> for RemoteCallFilter {
+	fn filter(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::XYK(pallet_xyk::Call::sell(..)) | Call::XYK(pallet_xyk::Call::buy(..)) | Call::XcmTrade(..)
+		)
+	}
+}
+
+/// Rejects messages which `Transact` a call not allowed by `RemoteCallFilter`, then applies `Inner`.
+///
+/// Calls of the relay chain itself are not filtered.
+pub struct DenyDisallowedTransact<Inner>(PhantomData<Inner>);
+
+impl<Inner> DenyDisallowedTransact<Inner> {
+	fn transact_allowed<C>(trusted: bool, message: &Xcm<C>) -> bool {
+		match message {
+			Xcm::Transact { call, .. } => {
+				trusted
+					|| Vec::<u8>::decode(&mut &call.encode()[..])
+						.ok()
+						.and_then(|encoded| Call::decode(&mut &encoded[..]).ok())
+						.map_or(false, |call| RemoteCallFilter::filter(&call))
+			}
+			Xcm::RelayedFrom { message, .. } => Self::transact_allowed(false, message),
+			Xcm::WithdrawAsset { effects, .. }
+			| Xcm::ReserveAssetDeposit { effects, .. }
+			| Xcm::TeleportAsset { effects, .. } => effects.iter().all(|effect| match effect {
+				Order::BuyExecution { xcm, .. } => xcm.iter().all(|message| Self::transact_allowed(trusted, message)),
+				_ => true,
+			}),
+			_ => true,
+		}
+	}
+}
+
+impl<Inner: ShouldExecute> ShouldExecute for DenyDisallowedTransact<Inner> {
+	fn should_execute<C>(
+		origin: &MultiLocation,
+		top_level: bool,
+		message: &Xcm<C>,
+		shallow_weight: Weight,
+		weight_credit: &mut Weight,
+	) -> Result<(), ()> {
+		if !Self::transact_allowed(*origin == X1(Parent), message) {
+			return Err(());
+		}
+
+		Inner::should_execute(origin, top_level, message, shallow_weight, weight_credit)
+	}
+}
+
+/// Only paid execution is allowed, except for the relay chain and its governance.
+pub type Barrier = DenyDisallowedTransact<(
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<All<MultiLocation>>,
+	AllowUnpaidExecutionFrom<ParentOrParentsUnitPlurality>,
+)>;
+
+/// Asset used to buy weight in `MultiCurrencyTrader`.
+struct TraderPayment {
+	location: MultiLocation,
+	asset_id: AssetId,
+	/// Amount of the asset paid.
+	amount: Balance,
+	/// BSX value of `amount` at the time of purchase.
+	fee: Balance,
+}
+
+/// Buys XCM execution weight with BSX or with any asset which has an XYK pool with BSX.
+///
+/// The weight is priced in BSX by `WeightToFee` and converted to the paying asset at the XYK spot
+/// price, the same way `MultiTransactionPayment` prices local fees. The relay chain token is
+/// accepted at `RelayTokenFeeRate` until it has a pool, so that it can be deposited to a new chain.
+/// A single asset pays for all the weight bought by one message and unused weight is refunded in
+/// that asset at the purchase price.
+///
+/// BSX fees are split by `DealWithFees`, fees paid in other assets go to the treasury.
+pub struct MultiCurrencyTrader {
+	weight: Weight,
+	payment: Option<TraderPayment>,
+}
+
+impl MultiCurrencyTrader {
+	/// Amount of `asset_id` worth `fee` BSX.
+	fn price(asset_id: AssetId, fee: Balance) -> Option<Balance> {
+		if asset_id == CORE_ASSET_ID {
+			return Some(fee);
+		}
+
+		let pair = AssetPair {
+			asset_in: asset_id,
+			asset_out: CORE_ASSET_ID,
+		};
+		if !XYK::exists(pair) {
+			return if asset_id == RelayChainAssetId::get() {
+				Some(RelayTokenFeeRate::get().saturating_mul_int(fee))
+			} else {
+				None
+			};
+		}
+
+		let amount = XYK::get_spot_price_unchecked(CORE_ASSET_ID, asset_id, fee);
+		// A zero price means the pool is empty, execution must not be free.
+		if amount.is_zero() && !fee.is_zero() {
+			None
+		} else {
+			Some(amount)
+		}
+	}
+}
+
+impl WeightTrader for MultiCurrencyTrader {
+	fn new() -> Self {
+		Self {
+			weight: 0,
+			payment: None,
+		}
+	}
+
+	fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+		let fee = WeightToFee::calc(&weight);
+
+		for asset in payment.fungible_assets_iter() {
+			let location = match asset {
+				MultiAsset::ConcreteFungible { id, .. } => id,
+				_ => continue,
+			};
+			if matches!(&self.payment, Some(paid) if paid.location != location) {
+				continue;
+			}
+			let asset_id = match CurrencyIdConvert::convert(location.clone()) {
+				Some(asset_id) => asset_id,
+				None => continue,
+			};
+			let amount = match Self::price(asset_id, fee) {
+				Some(amount) => amount,
+				None => continue,
+			};
+
+			let required = MultiAsset::ConcreteFungible {
+				id: location.clone(),
+				amount,
+			};
+			if let Ok(unused) = payment.clone().less(required) {
+				self.weight = self.weight.saturating_add(weight);
+				let paid = self.payment.get_or_insert(TraderPayment {
+					location,
+					asset_id,
+					amount: 0,
+					fee: 0,
+				});
+				paid.amount = paid.amount.saturating_add(amount);
+				paid.fee = paid.fee.saturating_add(fee);
+				return Ok(unused);
+			}
+		}
+
+		Err(XcmError::TooExpensive)
+	}
+
+	fn refund_weight(&mut self, weight: Weight) -> MultiAsset {
+		let weight = weight.min(self.weight);
+		let paid = match self.payment.as_mut() {
+			Some(paid) if !paid.fee.is_zero() => paid,
+			_ => return MultiAsset::None,
+		};
+
+		let fee = WeightToFee::calc(&weight).min(paid.fee);
+		let amount = multiply_by_rational(paid.amount, fee, paid.fee)
+			.unwrap_or_default()
+			.min(paid.amount);
+
+		self.weight -= weight;
+		paid.amount -= amount;
+		paid.fee -= fee;
+
+		MultiAsset::ConcreteFungible {
+			id: paid.location.clone(),
+			amount,
+		}
+	}
+}
+
+impl Drop for MultiCurrencyTrader {
+	fn drop(&mut self) {
+		let paid = match self.payment.take() {
+			Some(paid) if !paid.amount.is_zero() => paid,
+			_ => return,
+		};
+
+		// The payment was withdrawn from the holding register, so the fee is minted again.
+		if paid.asset_id == CORE_ASSET_ID {
+			DealWithFees::on_unbalanced(Balances::issue(paid.amount));
+		} else {
+			let _ = Currencies::deposit(paid.asset_id, &TreasuryAccount::get(), paid.amount);
+		}
+	}
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type Call = Call;
+	type XcmSender = XcmRouter;
+	type AssetTransactor = LocalAssetTransactor;
+	type OriginConverter = XcmOriginToTransactDispatchOrigin;
+	// Assets are only accepted as reserve deposits from the chain they originate from.
+	type IsReserve = MultiNativeAsset;
+	// Teleports are not trusted from anyone, `()` matches no location.
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+	type Trader = MultiCurrencyTrader;
+	type ResponseHandler = ();
+}
+
+/// Denies all teleports, assets leave this chain only by reserve transfers.
+///
+/// `()` is not used for this because it accepts everything.
+pub struct NoTeleports;
+impl Filter<(MultiLocation, Vec<MultiAsset>)> for NoTeleports {
+	fn filter(_: &(MultiLocation, Vec<MultiAsset>)) -> bool {
+		false
+	}
+}
+
+/// Converts a local signed origin into an `AccountId32` location.
+pub type LocalOriginToLocation = (SignedToAccountId32<Origin, AccountId, RelayNetwork>,);
+
+/// Routes outgoing messages to the relay chain through UMP and to siblings through XCMP.
+pub type XcmRouter = (cumulus_primitives_utility::ParentAsUmp<ParachainSystem>, XcmpQueue);
+
+impl pallet_xcm::Config for Runtime {
+	type Event = Event;
+	type SendXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type XcmRouter = XcmRouter;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type XcmExecuteFilter = All<(MultiLocation, Xcm<Call>)>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = NoTeleports;
+	type XcmReserveTransferFilter = All<(MultiLocation, Vec<MultiAsset>)>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+}
+
+impl cumulus_pallet_xcm::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ChannelInfo = ParachainSystem;
+}
+
+impl cumulus_pallet_dmp_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ExecuteOverweightOrigin = EnsureRootOrMoreThanHalfCouncil;
+}
+
+impl orml_xtokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type CurrencyId = AssetId;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type AccountId32Convert = AccountId32Convert;
+	type SelfLocation = SelfLocation;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call>;
+	type BaseXcmWeight = BaseXcmWeight;
+}
+
+impl orml_unknown_tokens::Config for Runtime {
+	type Event = Event;
+}
+
+parameter_types! {
+	pub const RelayReserveXcmWeight: Weight = 1_000_000_000;
+}
+
+impl pallet_relay_reserve::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type RelayChainAssetId = RelayChainAssetId;
+	type AccountIdConvert = AccountId32Convert;
+	type XcmSender = XcmRouter;
+	type RelayXcmWeight = RelayReserveXcmWeight;
+	type WeightInfo = pallet_relay_reserve::weights::BasiliskWeight<Runtime>;
+}
+
+/// XCM origin of the sender of a `Transact`.
+///
+/// Benchmarks dispatch as the relay chain, whose account and response route exist without open
+/// HRMP channels.
+pub struct EnsureRemoteXcm;
+impl EnsureOrigin<Origin> for EnsureRemoteXcm {
+	type Success = MultiLocation;
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		pallet_xcm::EnsureXcm::<All<MultiLocation>>::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		pallet_xcm::Origin::Xcm(X1(Parent)).into()
+	}
+}
+
+impl pallet_xcm_trade::Config for Runtime {
+	type Event = Event;
+	type RemoteOrigin = EnsureRemoteXcm;
+	type LocationToAccountId = LocationToAccountId;
+	type AMMPool = XYK;
+	type Currency = Currencies;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type XcmSender = XcmRouter;
+	type WeightInfo = pallet_xcm_trade::weights::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Index of the `Hrmp` pallet in the runtime of the relay chain, 60 in the Kusama and Polkadot
+	/// runtimes.
+	///
+	/// Kept in storage under `twox_128(":HrmpPalletIndex:")` so it can be changed with
+	/// `system.set_storage` when the relay chain runtime is changed.
+	pub storage HrmpPalletIndex: u8 = 60;
+	/// Relay chain token paid for the HRMP calls, 0.1 KSM (12 decimals). It has to cover the
+	/// execution on the relay chain, the unused part is deposited back to the sovereign account.
+	///
+	/// Kept in storage under `twox_128(":HrmpRelayFee:")` so it can be changed with
+	/// `system.set_storage` when the fees of the relay chain change.
+	pub storage HrmpRelayFee: u128 = 100_000_000_000;
+	pub const HrmpRelayTransactWeight: Weight = 1_000_000_000;
+	pub const HrmpRelayXcmWeight: Weight = 1_000_000_000;
+}
+
+impl pallet_hrmp_channels::Config for Runtime {
+	type Event = Event;
+	type UpdateOrigin = EnsureRootOrMoreThanHalfCouncil;
+	type SelfParaId = ParachainInfo;
+	type XcmSender = XcmRouter;
+	type HrmpPalletIndex = HrmpPalletIndex;
+	type RelayFee = HrmpRelayFee;
+	type RelayTransactWeight = HrmpRelayTransactWeight;
+	type RelayXcmWeight = HrmpRelayXcmWeight;
+	type WeightInfo = pallet_hrmp_channels::weights::BasiliskWeight<Runtime>;
+}